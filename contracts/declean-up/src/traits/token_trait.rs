@@ -18,9 +18,9 @@ pub trait TokenInterface {
     ///   `from`.
     /// * `amount` - The tokens to be made available to `spender`.
     /// * `expiration_ledger` - The ledger number where this allowance expires. Cannot
-    ///    be less than the current ledger number unless the amount is being set to 0.
-    ///    An expired entry (where expiration_ledger < the current ledger number)
-    ///    should be treated as a 0 amount allowance.
+    ///   be less than the current ledger number unless the amount is being set to 0.
+    ///   An expired entry (where expiration_ledger < the current ledger number)
+    ///   should be treated as a 0 amount allowance.
     ///
     /// # Events
     ///
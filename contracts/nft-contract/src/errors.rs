@@ -8,4 +8,7 @@ pub enum NFTError {
     NotAuthorized = 3,
     TokenDoesNotExist = 4,
     InvalidTokenOwner = 5,
+    NoPendingAdmin = 6,
+    InvalidExpirationLedger = 7,
+    IndexOutOfBounds = 8,
 }
\ No newline at end of file
@@ -3,7 +3,7 @@ mod errors;
 mod types;
 mod storage;
 
-use soroban_sdk::{contract, contractimpl, vec, Env, String, Vec, Address, symbol_short};
+use soroban_sdk::{contract, contractimpl, Env, String, Address, BytesN, Vec, symbol_short};
 use storage::NFTStorageLayer;
 use crate::{errors::NFTError, types::*};
 
@@ -31,6 +31,7 @@ impl NFTContract {
 
         NFTStorageLayer::set_admin(&env, &admin);
         env.storage().instance().set(&COUNTER_KEY, &0u32);
+        NFTStorageLayer::extend_instance(&env);
 
         let topics = (symbol_short!("init"), &admin);
         env.events().publish(topics, &admin);
@@ -38,17 +39,61 @@ impl NFTContract {
         Ok(())
     }
 
+    /// First step of an admin handover: the current admin nominates `new_admin`.
+    /// Proposing again replaces any earlier nomination.
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), NFTError> {
+        NFTStorageLayer::extend_instance(&env);
 
+        let admin = NFTStorageLayer::retrieve_admin(&env);
+
+        admin.require_auth();
+
+        NFTStorageLayer::set_pending_admin(&env, &new_admin);
+
+        let topics = (symbol_short!("adm_prop"), admin);
+        env.events().publish(topics, new_admin);
+
+        Ok(())
+    }
+
+    /// Second step of an admin handover: the nominated address takes over.
+    pub fn accept_admin(env: Env) -> Result<(), NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+
+        let new_admin = NFTStorageLayer::retrieve_pending_admin(&env)
+            .ok_or(NFTError::NoPendingAdmin)?;
+
+        new_admin.require_auth();
+
+        let old_admin = NFTStorageLayer::retrieve_admin(&env);
+        NFTStorageLayer::set_admin(&env, &new_admin);
+        NFTStorageLayer::remove_pending_admin(&env);
+
+        let topics = (symbol_short!("adm_acc"), old_admin);
+        env.events().publish(topics, new_admin);
+
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Address {
+        NFTStorageLayer::extend_instance(&env);
+        NFTStorageLayer::retrieve_admin(&env)
+    }
+
+
+    /// Mints a new token to `to` and returns its id. `caller` must be the
+    /// admin or an authorized updater.
     pub fn mint(env: Env, 
+                caller: Address,
                 to: Address,
                 name: String,
                 description: String,
                 image_url: String,
                 level: u32,
-    ) -> Result<(), NFTError> {
-        let admin = NFTStorageLayer::retrieve_admin(&env);
+    ) -> Result<u32, NFTError> {
+        Self::require_updater(&env, &caller)?;
 
-        admin.require_auth();
+        NFTStorageLayer::extend_instance(&env);
 
         let token_id: u32 = env.storage().instance().get(&COUNTER_KEY).unwrap();
         env.storage().instance().set(&COUNTER_KEY, &(token_id + 1));
@@ -58,17 +103,97 @@ impl NFTContract {
             description,
             image_url,
             level,
-            owner: to.clone()
         };
 
         NFTStorageLayer::set_token_owner(&env, &token_id, &to);
         NFTStorageLayer::set_token_metadata(&env, &token_id, &metadata);
 
-        NFTStorageLayer::increment_balance(&env, &to);
+        NFTStorageLayer::add_owned_token(&env, &to, &token_id);
+        NFTStorageLayer::add_live_token(&env, &token_id);
 
         let mint_topic = (symbol_short!("mint"), to);
         env.events().publish(mint_topic, token_id);
 
+        Ok(token_id)
+    }
+
+    /// Allows `updater` (typically another contract) to mint tokens and change
+    /// token metadata, without handing it the admin role.
+    pub fn add_updater(env: Env, updater: Address) -> Result<(), NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+
+        let admin = NFTStorageLayer::retrieve_admin(&env);
+
+        admin.require_auth();
+
+        NFTStorageLayer::set_updater(&env, &updater, true);
+
+        let topics = (symbol_short!("upd_add"), admin);
+        env.events().publish(topics, updater);
+
+        Ok(())
+    }
+
+    pub fn remove_updater(env: Env, updater: Address) -> Result<(), NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+
+        let admin = NFTStorageLayer::retrieve_admin(&env);
+
+        admin.require_auth();
+
+        NFTStorageLayer::set_updater(&env, &updater, false);
+
+        let topics = (symbol_short!("upd_rem"), admin);
+        env.events().publish(topics, updater);
+
+        Ok(())
+    }
+
+    pub fn is_updater(env: Env, updater: Address) -> bool {
+        NFTStorageLayer::extend_instance(&env);
+        NFTStorageLayer::is_updater(&env, &updater)
+    }
+
+    /// Changes the level of an existing token. `caller` must be the admin or
+    /// an authorized updater.
+    pub fn set_level(env: Env, caller: Address, token_id: u32, level: u32) -> Result<(), NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+
+        Self::require_updater(&env, &caller)?;
+
+        let mut metadata = Self::existing_metadata(&env, token_id)?;
+        let old_level = metadata.level;
+        metadata.level = level;
+
+        Self::store_update(&env, token_id, &metadata, old_level);
+
+        Ok(())
+    }
+
+    /// Replaces the descriptive metadata and level of an existing token. The
+    /// owner is not affected. `caller` must be the admin or an authorized updater.
+    pub fn update_metadata(
+        env: Env,
+        caller: Address,
+        token_id: u32,
+        name: String,
+        description: String,
+        image_url: String,
+        level: u32,
+    ) -> Result<(), NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+
+        Self::require_updater(&env, &caller)?;
+
+        let mut metadata = Self::existing_metadata(&env, token_id)?;
+        let old_level = metadata.level;
+        metadata.name = name;
+        metadata.description = description;
+        metadata.image_url = image_url;
+        metadata.level = level;
+
+        Self::store_update(&env, token_id, &metadata, old_level);
+
         Ok(())
     }
 
@@ -79,7 +204,8 @@ impl NFTContract {
         to: Address,
         token_id: u32,
     ) -> Result<(), NFTError> {
-       
+        NFTStorageLayer::extend_instance(&env);
+
         let token_owner = NFTStorageLayer::get_token_owner(&env, &token_id)
             .ok_or(NFTError::TokenDoesNotExist)?;
         
@@ -90,32 +216,141 @@ impl NFTContract {
         
         from.require_auth();
 
-       
-        NFTStorageLayer::set_token_owner(&env, &token_id, &to);
-        
-        
-        NFTStorageLayer::decrement_balance(&env, &from);
-        NFTStorageLayer::increment_balance(&env, &to);
+        Self::move_token(&env, &from, &to, token_id);
 
-        
-        let topics = (symbol_short!("transfer"), from, to);
-        env.events().publish(topics, token_id);
+        Ok(())
+    }
+
+    /// Transfers `token_id` on behalf of `from`. `spender` must be the owner,
+    /// the token's approved operator or an operator approved for all of
+    /// `from`'s tokens.
+    pub fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        token_id: u32,
+    ) -> Result<(), NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+
+        let token_owner = NFTStorageLayer::get_token_owner(&env, &token_id)
+            .ok_or(NFTError::TokenDoesNotExist)?;
+
+        if token_owner != from {
+            return Err(NFTError::InvalidTokenOwner);
+        }
+
+        spender.require_auth();
+
+        let approved = spender == from
+            || NFTStorageLayer::get_approval(&env, &token_id)
+                .is_some_and(|approval| approval.operator == spender)
+            || NFTStorageLayer::is_operator_approved(&env, &from, &spender);
+        if !approved {
+            return Err(NFTError::NotAuthorized);
+        }
+
+        Self::move_token(&env, &from, &to, token_id);
 
         Ok(())
     }
 
+    /// Lets `operator` transfer `token_id` until `expiration_ledger`. Replaces
+    /// any earlier approval for the token; an `expiration_ledger` already in
+    /// the past revokes it instead.
+    pub fn approve(
+        env: Env,
+        owner: Address,
+        operator: Address,
+        token_id: u32,
+        expiration_ledger: u32,
+    ) -> Result<(), NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+
+        let token_owner = NFTStorageLayer::get_token_owner(&env, &token_id)
+            .ok_or(NFTError::TokenDoesNotExist)?;
+
+        if token_owner != owner {
+            return Err(NFTError::InvalidTokenOwner);
+        }
+
+        owner.require_auth();
+
+        let sequence = env.ledger().sequence();
+        if expiration_ledger < sequence {
+            NFTStorageLayer::remove_approval(&env, &token_id);
+        } else {
+            // The approval's temporary entry cannot outlive the network's max TTL
+            if expiration_ledger - sequence > env.storage().max_ttl() {
+                return Err(NFTError::InvalidExpirationLedger);
+            }
+
+            let approval = TokenApproval { operator: operator.clone(), expiration_ledger };
+            NFTStorageLayer::set_approval(&env, &token_id, &approval);
+        }
+
+        let topics = (symbol_short!("approve"), owner, token_id);
+        env.events().publish(topics, (operator, expiration_ledger));
+
+        Ok(())
+    }
+
+    /// Lets `operator` transfer every token `owner` holds now or later, or
+    /// revokes that right.
+    pub fn set_approval_for_all(
+        env: Env,
+        owner: Address,
+        operator: Address,
+        approved: bool,
+    ) -> Result<(), NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+
+        owner.require_auth();
+
+        NFTStorageLayer::set_operator_approval(&env, &owner, &operator, approved);
+
+        let topics = (symbol_short!("appr_all"), owner);
+        env.events().publish(topics, (operator, approved));
+
+        Ok(())
+    }
+
+    /// Returns the operator approved for `token_id`, if the approval has not expired.
+    pub fn get_approved(env: Env, token_id: u32) -> Option<Address> {
+        NFTStorageLayer::extend_instance(&env);
+        NFTStorageLayer::get_approval(&env, &token_id).map(|approval| approval.operator)
+    }
+
+    pub fn is_approved_for_all(env: Env, owner: Address, operator: Address) -> bool {
+        NFTStorageLayer::extend_instance(&env);
+        NFTStorageLayer::is_operator_approved(&env, &owner, &operator)
+    }
+
+    pub fn owner_of(env: Env, token_id: u32) -> Result<Address, NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+        NFTStorageLayer::get_token_owner(&env, &token_id)
+            .ok_or(NFTError::TokenDoesNotExist)
+    }
+
+    pub fn balance_of(env: Env, owner: Address) -> u32 {
+        NFTStorageLayer::extend_instance(&env);
+        NFTStorageLayer::get_balance(&env, &owner)
+    }
+
     pub fn get_metadata(env: Env, token_id: u32) -> NFTMetadata {
+        NFTStorageLayer::extend_instance(&env);
+
         let _owner = NFTStorageLayer::get_token_owner(&env, &token_id)
             .unwrap_or_else(|| panic!("NFT Token not found"));
 
-        let metadata = NFTStorageLayer::get_token_metadata(&env, &token_id)
-            .unwrap_or_else(|| panic!("NFT Metadata not found"));
-
-        metadata
+        NFTStorageLayer::get_token_metadata(&env, &token_id)
+            .unwrap_or_else(|| panic!("NFT Metadata not found"))
 
     }
 
     pub fn burn(env: Env, from: Address, token_id: u32) -> Result<(), NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+
         let token_owner = NFTStorageLayer::get_token_owner(&env, &token_id)
             .ok_or(NFTError::TokenDoesNotExist)?;
 
@@ -127,14 +362,13 @@ impl NFTContract {
         
         from.require_auth();
 
-         // Delete the token owner
-        env.storage().instance().remove(&TokenKey::TokenOwner(token_id));
+        // Delete the token owner, metadata and approval
+        NFTStorageLayer::remove_token(&env, &token_id);
+        NFTStorageLayer::remove_approval(&env, &token_id);
 
-        // Delete the token metadata
-        env.storage().instance().remove(&TokenKey::TokenMetadata(token_id));
-
-        // Decrease user's balance
-        NFTStorageLayer::decrement_balance(&env, &from);
+        // Drop it from the owner's list and the live-token index
+        NFTStorageLayer::remove_owned_token(&env, &from, &token_id);
+        NFTStorageLayer::remove_live_token(&env, &token_id);
 
         let topics = (symbol_short!("burn"), from);
         env.events().publish(topics, token_id);
@@ -142,6 +376,126 @@ impl NFTContract {
         Ok(())
 
     }
+
+    /// Returns up to `limit` of `owner`'s token ids, starting at position
+    /// `start`. Positions are reused as tokens leave the owner, so the order
+    /// is not stable across transfers. `limit` is capped at `MAX_PAGE_SIZE` to
+    /// stay within the per-transaction ledger read limit.
+    pub fn tokens_of_owner(env: Env, owner: Address, start: u32, limit: u32) -> Vec<u32> {
+        NFTStorageLayer::extend_instance(&env);
+
+        let end = NFTStorageLayer::get_balance(&env, &owner)
+            .min(start.saturating_add(limit.min(MAX_PAGE_SIZE)));
+
+        let mut tokens = Vec::new(&env);
+        for index in start..end {
+            if let Some(token_id) = NFTStorageLayer::get_owned_token(&env, &owner, index) {
+                tokens.push_back(token_id);
+            }
+        }
+        tokens
+    }
+
+    /// Number of live tokens: minted and not burned.
+    pub fn total_supply(env: Env) -> u32 {
+        NFTStorageLayer::extend_instance(&env);
+        NFTStorageLayer::get_total_supply(&env)
+    }
+
+    /// Returns the live token at `index` in `0..total_supply()`. Like
+    /// `tokens_of_owner`, positions are reused after burns.
+    pub fn token_by_index(env: Env, index: u32) -> Result<u32, NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+        NFTStorageLayer::get_live_token(&env, index).ok_or(NFTError::IndexOutOfBounds)
+    }
+
+    /// Moves the given tokens (and their owners' balances) from the old
+    /// instance-storage layout to persistent storage. Safe to call repeatedly
+    /// and in batches; tokens already migrated are skipped. Returns how many
+    /// tokens were moved. Minted ids run from 0 up to the mint counter; burned
+    /// ids are skipped. The original instance-storage build has no `upgrade`,
+    /// so this is not a way to move an existing deployment in place; see
+    /// `upgrade`.
+    pub fn migrate(env: Env, token_ids: Vec<u32>) -> Result<u32, NFTError> {
+        let admin = NFTStorageLayer::retrieve_admin(&env);
+
+        admin.require_auth();
+        NFTStorageLayer::extend_instance(&env);
+
+        let mut migrated = 0;
+        for token_id in token_ids.iter() {
+            if NFTStorageLayer::migrate_legacy_token(&env, &token_id) {
+                migrated += 1;
+            }
+        }
+
+        let topics = (symbol_short!("migrate"), admin);
+        env.events().publish(topics, migrated);
+
+        Ok(migrated)
+    }
+
+    /// Replaces the contract code with the uploaded wasm `new_wasm_hash`,
+    /// keeping storage. Deployments of builds without `upgrade`, including the
+    /// original instance-storage one, cannot replace their wasm and have to be
+    /// redeployed, re-minting their tokens from an off-chain snapshot of owners
+    /// and metadata.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), NFTError> {
+        NFTStorageLayer::extend_instance(&env);
+
+        let admin = NFTStorageLayer::retrieve_admin(&env);
+
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+
+        let topics = (symbol_short!("upgrade"), admin);
+        env.events().publish(topics, new_wasm_hash);
+
+        Ok(())
+    }
+}
+
+impl NFTContract {
+    /// Hands `token_id` from `from` to `to` and clears its approval.
+    fn move_token(env: &Env, from: &Address, to: &Address, token_id: u32) {
+        NFTStorageLayer::set_token_owner(env, &token_id, to);
+        NFTStorageLayer::remove_approval(env, &token_id);
+
+        NFTStorageLayer::remove_owned_token(env, from, &token_id);
+        NFTStorageLayer::add_owned_token(env, to, &token_id);
+
+        let topics = (symbol_short!("transfer"), from.clone(), to.clone());
+        env.events().publish(topics, token_id);
+    }
+
+    fn require_updater(env: &Env, caller: &Address) -> Result<(), NFTError> {
+        caller.require_auth();
+
+        if *caller != NFTStorageLayer::retrieve_admin(env)
+            && !NFTStorageLayer::is_updater(env, caller)
+        {
+            return Err(NFTError::NotAuthorized);
+        }
+
+        Ok(())
+    }
+
+    fn existing_metadata(env: &Env, token_id: u32) -> Result<NFTMetadata, NFTError> {
+        NFTStorageLayer::get_token_owner(env, &token_id)
+            .ok_or(NFTError::TokenDoesNotExist)?;
+
+        NFTStorageLayer::get_token_metadata(env, &token_id)
+            .ok_or(NFTError::TokenDoesNotExist)
+    }
+
+    /// Saves updated metadata and emits the old and new level.
+    fn store_update(env: &Env, token_id: u32, metadata: &NFTMetadata, old_level: u32) {
+        NFTStorageLayer::set_token_metadata(env, &token_id, metadata);
+
+        let topics = (symbol_short!("update"), token_id);
+        env.events().publish(topics, (old_level, metadata.level));
+    }
 }
 
 mod test;
@@ -1,9 +1,19 @@
-use crate::types::{TokenKey, NFTMetadata, ADMIN_KEY};
-use soroban_sdk::{Address, Env};
+use crate::types::{
+    TokenKey, LegacyNFTMetadata, NFTMetadata, OperatorApprovalKey, OwnerTokenKey, TokenApproval,
+    ADMIN_KEY, SUPPLY_KEY, PENDING_ADMIN_KEY, INSTANCE_BUMP_AMOUNT,
+    INSTANCE_LIFETIME_THRESHOLD, TOKEN_BUMP_AMOUNT, TOKEN_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{Address, Env, IntoVal, TryFromVal, Val};
 
 pub struct NFTStorageLayer;
 
 impl NFTStorageLayer {
+    pub fn extend_instance(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
     pub fn set_admin(env: &Env, admin: &Address) {
         env.storage().instance().set(&ADMIN_KEY, admin);
     }
@@ -12,44 +22,212 @@ impl NFTStorageLayer {
         env.storage().instance().get(&ADMIN_KEY).unwrap()
     }
 
-    pub fn increment_balance(env: &Env, address: &Address) {
-        let balance: u32 = env
-            .storage()
-            .instance()
-            .get(&TokenKey::UserTokenBalance(address.clone()))
-            .unwrap_or(0);
-        env.storage()
-            .instance()
-            .set(&TokenKey::UserTokenBalance(address.clone()), &(balance + 1));
+    pub fn set_pending_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&PENDING_ADMIN_KEY, admin);
     }
 
-    pub fn decrement_balance(env: &Env, address: &Address) {
-        let balance: u32 = env
-            .storage()
-            .instance()
-            .get(&TokenKey::UserTokenBalance(address.clone()))
-            .unwrap_or(0);
-        env.storage()
-            .instance()
-            .set(&TokenKey::UserTokenBalance(address.clone()), &(balance - 1));
+    pub fn retrieve_pending_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&PENDING_ADMIN_KEY)
     }
 
+    pub fn remove_pending_admin(env: &Env) {
+        env.storage().instance().remove(&PENDING_ADMIN_KEY);
+    }
+
+    //==== Per-user and per-token data: persistent, every read and write bumps the TTL ====//
+    pub fn get_balance(env: &Env, address: &Address) -> u32 {
+        Self::get_persistent(env, &TokenKey::UserTokenBalance(address.clone())).unwrap_or(0)
+    }
 
     pub fn set_token_owner(env: &Env, token_id: &u32, owner: &Address) {
-        env.storage().instance().set(&TokenKey::TokenOwner(*token_id), owner);
+        Self::set_persistent(env, &TokenKey::TokenOwner(*token_id), owner);
     }
 
     pub fn get_token_owner(env: &Env, token_id: &u32) -> Option<Address> {
-        env.storage().instance().get(&TokenKey::TokenOwner(*token_id))
+        Self::get_persistent(env, &TokenKey::TokenOwner(*token_id))
     }
 
     pub fn set_token_metadata(env: &Env, token_id: &u32, metadata: &NFTMetadata) {
-        env.storage().instance().set(&TokenKey::TokenMetadata(*token_id), metadata);
+        Self::set_persistent(env, &TokenKey::TokenMetadata(*token_id), metadata);
     }
 
     pub fn get_token_metadata(env: &Env, token_id: &u32) -> Option<NFTMetadata> {
-        env.storage().instance().get(&TokenKey::TokenMetadata(*token_id))
+        Self::get_persistent(env, &TokenKey::TokenMetadata(*token_id))
+    }
+
+    pub fn remove_token(env: &Env, token_id: &u32) {
+        env.storage().persistent().remove(&TokenKey::TokenOwner(*token_id));
+        env.storage().persistent().remove(&TokenKey::TokenMetadata(*token_id));
+    }
+
+    pub fn set_updater(env: &Env, updater: &Address, authorized: bool) {
+        if authorized {
+            Self::set_persistent(env, &TokenKey::Updater(updater.clone()), &true);
+        } else {
+            env.storage().persistent().remove(&TokenKey::Updater(updater.clone()));
+        }
+    }
+
+    pub fn is_updater(env: &Env, updater: &Address) -> bool {
+        Self::get_persistent::<bool>(env, &TokenKey::Updater(updater.clone())).is_some()
+    }
+
+    //==== Enumeration: a removed token's slot is refilled with the last token in its list ====//
+    /// Appends `token_id` to `owner`'s list and bumps their balance.
+    pub fn add_owned_token(env: &Env, owner: &Address, token_id: &u32) {
+        let index = Self::get_balance(env, owner);
+        Self::set_persistent(env, &Self::owner_token_key(owner, index), token_id);
+        Self::set_persistent(env, &TokenKey::OwnerTokenIndex(*token_id), &index);
+        Self::set_persistent(env, &TokenKey::UserTokenBalance(owner.clone()), &(index + 1));
+    }
+
+    /// Removes `token_id` from `owner`'s list and lowers their balance.
+    pub fn remove_owned_token(env: &Env, owner: &Address, token_id: &u32) {
+        let index: u32 = Self::get_persistent(env, &TokenKey::OwnerTokenIndex(*token_id)).unwrap();
+        let last = Self::get_balance(env, owner) - 1;
+
+        if index != last {
+            let moved: u32 = Self::get_persistent(env, &Self::owner_token_key(owner, last)).unwrap();
+            Self::set_persistent(env, &Self::owner_token_key(owner, index), &moved);
+            Self::set_persistent(env, &TokenKey::OwnerTokenIndex(moved), &index);
+        }
+        env.storage().persistent().remove(&Self::owner_token_key(owner, last));
+        env.storage().persistent().remove(&TokenKey::OwnerTokenIndex(*token_id));
+        Self::set_persistent(env, &TokenKey::UserTokenBalance(owner.clone()), &last);
+    }
+
+    pub fn get_owned_token(env: &Env, owner: &Address, index: u32) -> Option<u32> {
+        Self::get_persistent(env, &Self::owner_token_key(owner, index))
+    }
+
+    pub fn get_total_supply(env: &Env) -> u32 {
+        env.storage().instance().get(&SUPPLY_KEY).unwrap_or(0)
+    }
+
+    /// Appends `token_id` to the live-token index and bumps the supply.
+    pub fn add_live_token(env: &Env, token_id: &u32) {
+        let index = Self::get_total_supply(env);
+        Self::set_persistent(env, &TokenKey::AllTokens(index), token_id);
+        Self::set_persistent(env, &TokenKey::AllTokensIndex(*token_id), &index);
+        env.storage().instance().set(&SUPPLY_KEY, &(index + 1));
+    }
+
+    /// Removes `token_id` from the live-token index and lowers the supply.
+    pub fn remove_live_token(env: &Env, token_id: &u32) {
+        let index: u32 = Self::get_persistent(env, &TokenKey::AllTokensIndex(*token_id)).unwrap();
+        let last = Self::get_total_supply(env) - 1;
+
+        if index != last {
+            let moved: u32 = Self::get_persistent(env, &TokenKey::AllTokens(last)).unwrap();
+            Self::set_persistent(env, &TokenKey::AllTokens(index), &moved);
+            Self::set_persistent(env, &TokenKey::AllTokensIndex(moved), &index);
+        }
+        env.storage().persistent().remove(&TokenKey::AllTokens(last));
+        env.storage().persistent().remove(&TokenKey::AllTokensIndex(*token_id));
+        env.storage().instance().set(&SUPPLY_KEY, &last);
+    }
+
+    pub fn get_live_token(env: &Env, index: u32) -> Option<u32> {
+        Self::get_persistent(env, &TokenKey::AllTokens(index))
+    }
+
+    fn owner_token_key(owner: &Address, index: u32) -> TokenKey {
+        TokenKey::OwnerToken(OwnerTokenKey { owner: owner.clone(), index })
+    }
+
+    //==== Approvals: per token in temporary storage, per operator in persistent storage ====//
+    /// Returns the token's approval unless it has expired.
+    pub fn get_approval(env: &Env, token_id: &u32) -> Option<TokenApproval> {
+        let approval: TokenApproval = env.storage().temporary().get(&TokenKey::Approval(*token_id))?;
+        if approval.expiration_ledger < env.ledger().sequence() {
+            return None;
+        }
+        Some(approval)
+    }
+
+    pub fn set_approval(env: &Env, token_id: &u32, approval: &TokenApproval) {
+        let key = TokenKey::Approval(*token_id);
+        env.storage().temporary().set(&key, approval);
+
+        // Keep the entry only until get_approval would start ignoring it
+        let live_for = approval.expiration_ledger - env.ledger().sequence();
+        env.storage().temporary().extend_ttl(&key, live_for, live_for);
+    }
+
+    pub fn remove_approval(env: &Env, token_id: &u32) {
+        env.storage().temporary().remove(&TokenKey::Approval(*token_id));
     }
 
-    
-}
\ No newline at end of file
+    pub fn set_operator_approval(env: &Env, owner: &Address, operator: &Address, approved: bool) {
+        let key = TokenKey::OperatorApproval(OperatorApprovalKey {
+            owner: owner.clone(),
+            operator: operator.clone(),
+        });
+        if approved {
+            Self::set_persistent(env, &key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    pub fn is_operator_approved(env: &Env, owner: &Address, operator: &Address) -> bool {
+        let key = TokenKey::OperatorApproval(OperatorApprovalKey {
+            owner: owner.clone(),
+            operator: operator.clone(),
+        });
+        Self::get_persistent::<bool>(env, &key).is_some()
+    }
+
+    fn get_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &TokenKey) -> Option<V> {
+        let value = env.storage().persistent().get(key);
+        if value.is_some() {
+            env.storage()
+                .persistent()
+                .extend_ttl(key, TOKEN_LIFETIME_THRESHOLD, TOKEN_BUMP_AMOUNT);
+        }
+        value
+    }
+
+    fn set_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &TokenKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        env.storage()
+            .persistent()
+            .extend_ttl(key, TOKEN_LIFETIME_THRESHOLD, TOKEN_BUMP_AMOUNT);
+    }
+
+    //==== Migration from the instance-storage layout ====//
+    /// Moves `token_id`'s owner and metadata from instance to persistent
+    /// storage and adds the token to the enumeration indexes, which also
+    /// rebuilds its owner's balance. The owner copy kept in the old metadata
+    /// is dropped; `TokenOwner` is authoritative. Returns `false` if the token
+    /// has no instance entries.
+    pub fn migrate_legacy_token(env: &Env, token_id: &u32) -> bool {
+        let instance = env.storage().instance();
+        let owner_key = TokenKey::TokenOwner(*token_id);
+        let Some(owner) = instance.get::<_, Address>(&owner_key) else {
+            return false;
+        };
+
+        Self::set_token_owner(env, token_id, &owner);
+        instance.remove(&owner_key);
+
+        let metadata_key = TokenKey::TokenMetadata(*token_id);
+        if let Some(legacy) = instance.get::<_, LegacyNFTMetadata>(&metadata_key) {
+            let metadata = NFTMetadata {
+                name: legacy.name,
+                description: legacy.description,
+                image_url: legacy.image_url,
+                level: legacy.level,
+            };
+            Self::set_token_metadata(env, token_id, &metadata);
+            instance.remove(&metadata_key);
+        }
+
+        // The old balance counter is superseded by the rebuilt owner list
+        instance.remove(&TokenKey::UserTokenBalance(owner.clone()));
+        Self::add_owned_token(env, &owner, token_id);
+        Self::add_live_token(env, token_id);
+
+        true
+    }
+}
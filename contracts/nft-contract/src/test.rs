@@ -1,6 +1,6 @@
 #![cfg(test)]
-use crate::{NFTContract, errors::NFTError};
-use soroban_sdk::{testutils::{Address as _}, vec, Address, Env, String, Vec};
+use crate::{NFTContract, errors::NFTError, types::{LegacyNFTMetadata, TokenKey}};
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, BytesN, Env, String};
 
 fn setup_contract() -> (Env, Address) {
     let env = Env::default();
@@ -9,7 +9,7 @@ fn setup_contract() -> (Env, Address) {
 }
 
 fn create_test_address(env: &Env) -> Address {
-    Address::generate(&env)
+    Address::generate(env)
 }
 
 #[test]
@@ -33,6 +33,7 @@ fn test_nft_minting() {
     let result = env.as_contract(&contract_id, || {
         NFTContract::mint(
             env.clone(),
+            admin.clone(),
             user.clone(),
             name.clone(),
             description.clone(),
@@ -47,7 +48,10 @@ fn test_nft_minting() {
         NFTContract::get_metadata(env.clone(), 0)
     });
 
-    assert_eq!(nft_token_detail.owner, user);
+    let owner = env.as_contract(&contract_id, || {
+        NFTContract::owner_of(env.clone(), 0)
+    });
+    assert_eq!(owner, Ok(user.clone()));
     assert_eq!(nft_token_detail.name, name);
     assert_eq!(nft_token_detail.description, description);
 }
@@ -76,6 +80,7 @@ fn test_admin_access_control() {
     let _result = env.as_contract(&contract_id, || {
         NFTContract::mint(
             env.clone(),
+            admin.clone(),
             user.clone(),
             name.clone(),
             description.clone(),
@@ -104,9 +109,10 @@ fn test_transfer_ownership() {
     let level: u32 = 7;
 
     env.mock_all_auths();
-    let _ = env.as_contract(&contract_id, || {
+    env.as_contract(&contract_id, || {
         NFTContract::mint(
             env.clone(),
+            admin.clone(),
             owner.clone(),
             name.clone(),
             description.clone(),
@@ -126,6 +132,29 @@ fn test_transfer_ownership() {
         )
     });
     assert!(result.is_ok());
+
+    // Ownership and balances follow the transfer
+    let (new_owner, metadata) = env.as_contract(&contract_id, || {
+        (
+            NFTContract::owner_of(env.clone(), 0),
+            NFTContract::get_metadata(env.clone(), 0),
+        )
+    });
+    assert_eq!(new_owner, Ok(nft_reciever.clone()));
+    assert_eq!(metadata.level, level);
+
+    let balances = env.as_contract(&contract_id, || {
+        (
+            NFTContract::balance_of(env.clone(), owner.clone()),
+            NFTContract::balance_of(env.clone(), nft_reciever.clone()),
+        )
+    });
+    assert_eq!(balances, (0, 1));
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::owner_of(env.clone(), 1)
+    });
+    assert_eq!(result, Err(NFTError::TokenDoesNotExist));
 }
 
 
@@ -146,9 +175,10 @@ fn test_transfer_ownership_with_wrong_tokenid_should_fail() {
     let level: u32 = 7;
 
     env.mock_all_auths();
-    let _ = env.as_contract(&contract_id, || {
+    env.as_contract(&contract_id, || {
         NFTContract::mint(
             env.clone(),
+            admin.clone(),
             owner.clone(),
             name.clone(),
             description.clone(),
@@ -189,9 +219,10 @@ fn test_transfer_ownership_for_non_owner_should_fail() {
     let level: u32 = 7;
 
     env.mock_all_auths();
-    let _ = env.as_contract(&contract_id, || {
+    env.as_contract(&contract_id, || {
         NFTContract::mint(
             env.clone(),
+            admin.clone(),
             owner.clone(),
             name.clone(),
             description.clone(),
@@ -233,6 +264,7 @@ fn test_burn_success() {
     env.as_contract(&contract_id, || {
         NFTContract::mint(
             env.clone(),
+            admin.clone(),
             user.clone(),
             name,
             description,
@@ -289,6 +321,7 @@ fn test_burn_token_by_non_owner_should_fail() {
     env.as_contract(&contract_id, || {
         NFTContract::mint(
             env.clone(),
+            admin.clone(),
             real_owner.clone(),
             name,
             description,
@@ -303,4 +336,632 @@ fn test_burn_token_by_non_owner_should_fail() {
     });
 
     assert!(result.is_err());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_admin_handover() {
+    let (env, contract_id) = setup_contract();
+    let admin = create_test_address(&env);
+    let new_admin = create_test_address(&env);
+    let user = create_test_address(&env);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::init_admin(env.clone(), admin.clone())
+    }).unwrap();
+
+    env.mock_all_auths();
+    env.as_contract(&contract_id, || {
+        NFTContract::propose_admin(env.clone(), new_admin.clone())
+    }).unwrap();
+
+    // Nothing changes until the nominee accepts
+    let current_admin = env.as_contract(&contract_id, || {
+        NFTContract::get_admin(env.clone())
+    });
+    assert_eq!(current_admin, admin);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::accept_admin(env.clone())
+    }).unwrap();
+
+    let current_admin = env.as_contract(&contract_id, || {
+        NFTContract::get_admin(env.clone())
+    });
+    assert_eq!(current_admin, new_admin);
+
+    // The nomination is consumed by the handover
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::accept_admin(env.clone())
+    });
+    assert_eq!(result, Err(NFTError::NoPendingAdmin));
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::mint(
+            env.clone(),
+            new_admin.clone(),
+            user.clone(),
+            String::from_str(&env, "NFT Token"),
+            String::from_str(&env, "NFT Description"),
+            String::from_str(&env, "https://pinata.com"),
+            1,
+        )
+    });
+    assert!(result.is_ok());
+}
+
+
+#[test]
+fn test_accept_admin_without_proposal_should_fail() {
+    let (env, contract_id) = setup_contract();
+    let admin = create_test_address(&env);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::init_admin(env.clone(), admin.clone())
+    }).unwrap();
+
+    env.mock_all_auths();
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::accept_admin(env.clone())
+    });
+
+    assert_eq!(result, Err(NFTError::NoPendingAdmin));
+}
+
+
+#[test]
+#[should_panic(expected = "Unauthorized function call for address")]
+fn test_upgrade_by_non_admin_should_fail() {
+    let (env, contract_id) = setup_contract();
+    let admin = create_test_address(&env);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::init_admin(env.clone(), admin.clone())
+    }).unwrap();
+
+    env.mock_auths(&[]);
+    let _result = env.as_contract(&contract_id, || {
+        NFTContract::upgrade(env.clone(), BytesN::from_array(&env, &[0; 32]))
+    });
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call for address")]
+fn test_propose_admin_by_non_admin_should_fail() {
+    let (env, contract_id) = setup_contract();
+    let admin = create_test_address(&env);
+    let attacker = create_test_address(&env);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::init_admin(env.clone(), admin.clone())
+    }).unwrap();
+
+    env.mock_auths(&[]);
+    let _result = env.as_contract(&contract_id, || {
+        NFTContract::propose_admin(env.clone(), attacker.clone())
+    });
+}
+
+
+#[test]
+fn test_set_level() {
+    let (env, contract_id) = setup_contract();
+    let admin = create_test_address(&env);
+    let user = create_test_address(&env);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::init_admin(env.clone(), admin.clone())
+    }).unwrap();
+
+    env.mock_all_auths();
+    let token_id = env.as_contract(&contract_id, || {
+        NFTContract::mint(
+            env.clone(),
+            admin.clone(),
+            user.clone(),
+            String::from_str(&env, "NFT Token"),
+            String::from_str(&env, "NFT Description"),
+            String::from_str(&env, "https://pinata.com"),
+            1,
+        )
+    }).unwrap();
+    assert_eq!(token_id, 0);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::set_level(env.clone(), admin.clone(), token_id, 3)
+    }).unwrap();
+
+    let metadata = env.as_contract(&contract_id, || {
+        NFTContract::get_metadata(env.clone(), token_id)
+    });
+    assert_eq!(metadata.level, 3);
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::set_level(env.clone(), admin.clone(), 9, 3)
+    });
+    assert_eq!(result, Err(NFTError::TokenDoesNotExist));
+}
+
+
+#[test]
+fn test_updater_can_update_metadata() {
+    let (env, contract_id) = setup_contract();
+    let admin = create_test_address(&env);
+    let updater = create_test_address(&env);
+    let outsider = create_test_address(&env);
+    let user = create_test_address(&env);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::init_admin(env.clone(), admin.clone())
+    }).unwrap();
+
+    env.mock_all_auths();
+    let token_id = env.as_contract(&contract_id, || {
+        NFTContract::mint(
+            env.clone(),
+            admin.clone(),
+            user.clone(),
+            String::from_str(&env, "NFT Token"),
+            String::from_str(&env, "NFT Description"),
+            String::from_str(&env, "https://pinata.com"),
+            1,
+        )
+    }).unwrap();
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::set_level(env.clone(), updater.clone(), token_id, 2)
+    });
+    assert_eq!(result, Err(NFTError::NotAuthorized));
+
+    env.as_contract(&contract_id, || {
+        NFTContract::add_updater(env.clone(), updater.clone())
+    }).unwrap();
+
+    let name = String::from_str(&env, "Level Two");
+    let image_url = String::from_str(&env, "https://pinata.com/2");
+    env.as_contract(&contract_id, || {
+        NFTContract::update_metadata(
+            env.clone(),
+            updater.clone(),
+            token_id,
+            name.clone(),
+            String::from_str(&env, "NFT Description"),
+            image_url.clone(),
+            2,
+        )
+    }).unwrap();
+
+    let metadata = env.as_contract(&contract_id, || {
+        NFTContract::get_metadata(env.clone(), token_id)
+    });
+    assert_eq!(metadata.name, name);
+    assert_eq!(metadata.image_url, image_url);
+    assert_eq!(metadata.level, 2);
+    let owner = env.as_contract(&contract_id, || {
+        NFTContract::owner_of(env.clone(), token_id)
+    });
+    assert_eq!(owner, Ok(user.clone()));
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::set_level(env.clone(), outsider.clone(), token_id, 5)
+    });
+    assert_eq!(result, Err(NFTError::NotAuthorized));
+
+    // Updaters can also mint, outsiders cannot
+    let minted = env.as_contract(&contract_id, || {
+        NFTContract::mint(
+            env.clone(),
+            updater.clone(),
+            user.clone(),
+            String::from_str(&env, "NFT Token"),
+            String::from_str(&env, "NFT Description"),
+            String::from_str(&env, "https://pinata.com"),
+            1,
+        )
+    });
+    assert_eq!(minted, Ok(token_id + 1));
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::mint(
+            env.clone(),
+            outsider.clone(),
+            user.clone(),
+            String::from_str(&env, "NFT Token"),
+            String::from_str(&env, "NFT Description"),
+            String::from_str(&env, "https://pinata.com"),
+            1,
+        )
+    });
+    assert_eq!(result, Err(NFTError::NotAuthorized));
+
+    env.as_contract(&contract_id, || {
+        NFTContract::remove_updater(env.clone(), updater.clone())
+    }).unwrap();
+
+    let is_updater = env.as_contract(&contract_id, || {
+        NFTContract::is_updater(env.clone(), updater.clone())
+    });
+    assert!(!is_updater);
+}
+
+
+#[test]
+fn test_token_data_is_persistent() {
+    let (env, contract_id) = setup_contract();
+    let admin = create_test_address(&env);
+    let user = create_test_address(&env);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::init_admin(env.clone(), admin.clone())
+    }).unwrap();
+
+    env.mock_all_auths();
+    let token_id = env.as_contract(&contract_id, || {
+        NFTContract::mint(
+            env.clone(),
+            admin.clone(),
+            user.clone(),
+            String::from_str(&env, "NFT Token"),
+            String::from_str(&env, "NFT Description"),
+            String::from_str(&env, "https://pinata.com"),
+            1,
+        )
+    }).unwrap();
+
+    env.as_contract(&contract_id, || {
+        let instance = env.storage().instance();
+        let persistent = env.storage().persistent();
+
+        assert!(!instance.has(&TokenKey::TokenOwner(token_id)));
+        assert!(persistent.has(&TokenKey::TokenOwner(token_id)));
+        assert!(persistent.has(&TokenKey::TokenMetadata(token_id)));
+        assert!(persistent.has(&TokenKey::UserTokenBalance(user.clone())));
+    });
+}
+
+#[test]
+fn test_migrate_legacy_tokens() {
+    let (env, contract_id) = setup_contract();
+    let admin = create_test_address(&env);
+    let user = create_test_address(&env);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::init_admin(env.clone(), admin.clone())
+    }).unwrap();
+
+    // Two tokens minted under the old instance-storage layout
+    env.as_contract(&contract_id, || {
+        let instance = env.storage().instance();
+        for token_id in 0..2u32 {
+            let metadata = LegacyNFTMetadata {
+                name: String::from_str(&env, "NFT Token"),
+                description: String::from_str(&env, "NFT Description"),
+                image_url: String::from_str(&env, "https://pinata.com"),
+                level: token_id,
+                owner: user.clone(),
+            };
+            instance.set(&TokenKey::TokenOwner(token_id), &user);
+            instance.set(&TokenKey::TokenMetadata(token_id), &metadata);
+        }
+        instance.set(&TokenKey::UserTokenBalance(user.clone()), &2u32);
+    });
+
+    env.mock_all_auths();
+    let migrated = env.as_contract(&contract_id, || {
+        NFTContract::migrate(env.clone(), vec![&env, 0, 1, 2])
+    }).unwrap();
+    assert_eq!(migrated, 2);
+
+    // Running it again is a no-op
+    let migrated = env.as_contract(&contract_id, || {
+        NFTContract::migrate(env.clone(), vec![&env, 0, 1])
+    }).unwrap();
+    assert_eq!(migrated, 0);
+
+    env.as_contract(&contract_id, || {
+        assert!(!env.storage().instance().has(&TokenKey::TokenOwner(0)));
+        assert!(!env.storage().instance().has(&TokenKey::UserTokenBalance(user.clone())));
+    });
+
+    let metadata = env.as_contract(&contract_id, || {
+        NFTContract::get_metadata(env.clone(), 1)
+    });
+    assert_eq!(metadata.level, 1);
+
+    // Migrated tokens are enumerable
+    let (supply, tokens) = env.as_contract(&contract_id, || {
+        (
+            NFTContract::total_supply(env.clone()),
+            NFTContract::tokens_of_owner(env.clone(), user.clone(), 0, 10),
+        )
+    });
+    assert_eq!(supply, 2);
+    assert_eq!(tokens, vec![&env, 0, 1]);
+
+    // Migrated tokens behave like new ones
+    let other = create_test_address(&env);
+    env.as_contract(&contract_id, || {
+        NFTContract::transfer(env.clone(), user.clone(), other.clone(), 0)
+    }).unwrap();
+    env.as_contract(&contract_id, || {
+        assert_eq!(
+            env.storage().persistent().get::<_, u32>(&TokenKey::UserTokenBalance(user.clone())),
+            Some(1)
+        );
+    });
+}
+
+
+/// Initializes the contract with a fresh admin and mints one token to `to`.
+fn mint_to(env: &Env, contract_id: &Address, to: &Address) -> u32 {
+    let admin = create_test_address(env);
+
+    env.as_contract(contract_id, || {
+        NFTContract::init_admin(env.clone(), admin.clone())
+    }).unwrap();
+
+    env.mock_all_auths();
+    env.as_contract(contract_id, || {
+        NFTContract::mint(
+            env.clone(),
+            admin.clone(),
+            to.clone(),
+            String::from_str(env, "NFT Token"),
+            String::from_str(env, "NFT Description"),
+            String::from_str(env, "https://pinata.com"),
+            1,
+        )
+    }).unwrap()
+}
+
+#[test]
+fn test_approved_operator_can_transfer_once() {
+    let (env, contract_id) = setup_contract();
+    let owner = create_test_address(&env);
+    let operator = create_test_address(&env);
+    let buyer = create_test_address(&env);
+    let token_id = mint_to(&env, &contract_id, &owner);
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::transfer_from(env.clone(), operator.clone(), owner.clone(), buyer.clone(), token_id)
+    });
+    assert_eq!(result, Err(NFTError::NotAuthorized));
+
+    env.as_contract(&contract_id, || {
+        NFTContract::approve(env.clone(), owner.clone(), operator.clone(), token_id, 100)
+    }).unwrap();
+
+    let approved = env.as_contract(&contract_id, || {
+        NFTContract::get_approved(env.clone(), token_id)
+    });
+    assert_eq!(approved, Some(operator.clone()));
+
+    env.as_contract(&contract_id, || {
+        NFTContract::transfer_from(env.clone(), operator.clone(), owner.clone(), buyer.clone(), token_id)
+    }).unwrap();
+
+    let new_owner = env.as_contract(&contract_id, || {
+        NFTContract::owner_of(env.clone(), token_id)
+    });
+    assert_eq!(new_owner, Ok(buyer.clone()));
+
+    // The approval does not survive the transfer
+    let approved = env.as_contract(&contract_id, || {
+        NFTContract::get_approved(env.clone(), token_id)
+    });
+    assert_eq!(approved, None);
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::transfer_from(env.clone(), operator.clone(), buyer.clone(), owner.clone(), token_id)
+    });
+    assert_eq!(result, Err(NFTError::NotAuthorized));
+}
+
+#[test]
+fn test_approval_expires() {
+    let (env, contract_id) = setup_contract();
+    let owner = create_test_address(&env);
+    let operator = create_test_address(&env);
+    let token_id = mint_to(&env, &contract_id, &owner);
+
+    env.ledger().set_sequence_number(50);
+    let too_late = 50 + env.as_contract(&contract_id, || env.storage().max_ttl()) + 1;
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::approve(env.clone(), owner.clone(), operator.clone(), token_id, too_late)
+    });
+    assert_eq!(result, Err(NFTError::InvalidExpirationLedger));
+
+    env.as_contract(&contract_id, || {
+        NFTContract::approve(env.clone(), owner.clone(), operator.clone(), token_id, 60)
+    }).unwrap();
+
+    env.ledger().set_sequence_number(61);
+    let approved = env.as_contract(&contract_id, || {
+        NFTContract::get_approved(env.clone(), token_id)
+    });
+    assert_eq!(approved, None);
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::transfer_from(env.clone(), operator.clone(), owner.clone(), operator.clone(), token_id)
+    });
+    assert_eq!(result, Err(NFTError::NotAuthorized));
+}
+
+#[test]
+fn test_revoke_approval() {
+    let (env, contract_id) = setup_contract();
+    let owner = create_test_address(&env);
+    let operator = create_test_address(&env);
+    let token_id = mint_to(&env, &contract_id, &owner);
+
+    env.ledger().set_sequence_number(50);
+    env.as_contract(&contract_id, || {
+        NFTContract::approve(env.clone(), owner.clone(), operator.clone(), token_id, 100)
+    }).unwrap();
+
+    // An expiration in the past revokes the approval
+    env.as_contract(&contract_id, || {
+        NFTContract::approve(env.clone(), owner.clone(), operator.clone(), token_id, 49)
+    }).unwrap();
+
+    let approved = env.as_contract(&contract_id, || {
+        NFTContract::get_approved(env.clone(), token_id)
+    });
+    assert_eq!(approved, None);
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::transfer_from(env.clone(), operator.clone(), owner.clone(), operator.clone(), token_id)
+    });
+    assert_eq!(result, Err(NFTError::NotAuthorized));
+}
+
+#[test]
+fn test_approval_for_all() {
+    let (env, contract_id) = setup_contract();
+    let owner = create_test_address(&env);
+    let operator = create_test_address(&env);
+    let buyer = create_test_address(&env);
+    let token_id = mint_to(&env, &contract_id, &owner);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::set_approval_for_all(env.clone(), owner.clone(), operator.clone(), true)
+    }).unwrap();
+
+    let approved = env.as_contract(&contract_id, || {
+        NFTContract::is_approved_for_all(env.clone(), owner.clone(), operator.clone())
+    });
+    assert!(approved);
+
+    env.as_contract(&contract_id, || {
+        NFTContract::transfer_from(env.clone(), operator.clone(), owner.clone(), buyer.clone(), token_id)
+    }).unwrap();
+
+    // Operator rights cover the owner's tokens, not the ones it gave away
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::transfer_from(env.clone(), operator.clone(), buyer.clone(), owner.clone(), token_id)
+    });
+    assert_eq!(result, Err(NFTError::NotAuthorized));
+
+    env.as_contract(&contract_id, || {
+        NFTContract::set_approval_for_all(env.clone(), owner.clone(), operator.clone(), false)
+    }).unwrap();
+
+    let approved = env.as_contract(&contract_id, || {
+        NFTContract::is_approved_for_all(env.clone(), owner.clone(), operator.clone())
+    });
+    assert!(!approved);
+}
+
+#[test]
+fn test_approve_errors_and_burn_clears_approval() {
+    let (env, contract_id) = setup_contract();
+    let owner = create_test_address(&env);
+    let operator = create_test_address(&env);
+    let token_id = mint_to(&env, &contract_id, &owner);
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::approve(env.clone(), operator.clone(), operator.clone(), token_id, 100)
+    });
+    assert_eq!(result, Err(NFTError::InvalidTokenOwner));
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::approve(env.clone(), owner.clone(), operator.clone(), 9, 100)
+    });
+    assert_eq!(result, Err(NFTError::TokenDoesNotExist));
+
+    env.as_contract(&contract_id, || {
+        NFTContract::approve(env.clone(), owner.clone(), operator.clone(), token_id, 100)
+    }).unwrap();
+    env.as_contract(&contract_id, || {
+        NFTContract::burn(env.clone(), owner.clone(), token_id)
+    }).unwrap();
+
+    let approved = env.as_contract(&contract_id, || {
+        NFTContract::get_approved(env.clone(), token_id)
+    });
+    assert_eq!(approved, None);
+}
+
+
+#[test]
+fn test_token_enumeration() {
+    let (env, contract_id) = setup_contract();
+    let alice = create_test_address(&env);
+    let bob = create_test_address(&env);
+    mint_to(&env, &contract_id, &alice);
+    let admin = env.as_contract(&contract_id, || {
+        NFTContract::get_admin(env.clone())
+    });
+
+    for _ in 0..3 {
+        env.as_contract(&contract_id, || {
+            NFTContract::mint(
+                env.clone(),
+                admin.clone(),
+                alice.clone(),
+                String::from_str(&env, "NFT Token"),
+                String::from_str(&env, "NFT Description"),
+                String::from_str(&env, "https://pinata.com"),
+                1,
+            )
+        }).unwrap();
+    }
+
+    let tokens = env.as_contract(&contract_id, || {
+        NFTContract::tokens_of_owner(env.clone(), alice.clone(), 0, 10)
+    });
+    assert_eq!(tokens, vec![&env, 0, 1, 2, 3]);
+
+    // The last token fills the slot freed by the transfer
+    env.as_contract(&contract_id, || {
+        NFTContract::transfer(env.clone(), alice.clone(), bob.clone(), 1)
+    }).unwrap();
+    let (alice_tokens, bob_tokens) = env.as_contract(&contract_id, || {
+        (
+            NFTContract::tokens_of_owner(env.clone(), alice.clone(), 0, 10),
+            NFTContract::tokens_of_owner(env.clone(), bob.clone(), 0, 10),
+        )
+    });
+    assert_eq!(alice_tokens, vec![&env, 0, 3, 2]);
+    assert_eq!(bob_tokens, vec![&env, 1]);
+
+    let page = env.as_contract(&contract_id, || {
+        NFTContract::tokens_of_owner(env.clone(), alice.clone(), 1, 1)
+    });
+    assert_eq!(page, vec![&env, 3]);
+
+    // Burns shrink the live supply but not the mint counter
+    env.as_contract(&contract_id, || {
+        NFTContract::burn(env.clone(), alice.clone(), 0)
+    }).unwrap();
+    let supply = env.as_contract(&contract_id, || {
+        NFTContract::total_supply(env.clone())
+    });
+    assert_eq!(supply, 3);
+
+    let live = env.as_contract(&contract_id, || {
+        [0, 1, 2].map(|index| NFTContract::token_by_index(env.clone(), index).unwrap())
+    });
+    assert_eq!(live, [3, 1, 2]);
+
+    let result = env.as_contract(&contract_id, || {
+        NFTContract::token_by_index(env.clone(), 3)
+    });
+    assert_eq!(result, Err(NFTError::IndexOutOfBounds));
+
+    let alice_tokens = env.as_contract(&contract_id, || {
+        NFTContract::tokens_of_owner(env.clone(), alice.clone(), 0, 10)
+    });
+    assert_eq!(alice_tokens, vec![&env, 2, 3]);
+
+    let token_id = env.as_contract(&contract_id, || {
+        NFTContract::mint(
+            env.clone(),
+            admin.clone(),
+            bob.clone(),
+            String::from_str(&env, "NFT Token"),
+            String::from_str(&env, "NFT Description"),
+            String::from_str(&env, "https://pinata.com"),
+            1,
+        )
+    }).unwrap();
+    assert_eq!(token_id, 4);
+}
@@ -2,7 +2,23 @@ use soroban_sdk::{contracttype, Address, String, Symbol, symbol_short};
 
 pub const COUNTER_KEY: Symbol = symbol_short!("COUNTER");
 pub const ADMIN_KEY: Symbol = symbol_short!("ADMIN");
+pub const PENDING_ADMIN_KEY: Symbol = symbol_short!("PEND_ADM");
+pub const SUPPLY_KEY: Symbol = symbol_short!("SUPPLY");
 
+/// A page reads one index entry per item, plus the instance and the owner's
+/// balance; 15 items matches the submission contract and stays well under the
+/// 40 ledger-entry reads a transaction may make.
+pub const MAX_PAGE_SIZE: u32 = 15;
+
+//==== Token records get a longer TTL than the instance holding admin and counters ====//
+pub const DAY_IN_LEDGERS: u32 = 17280;
+pub const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub const TOKEN_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const TOKEN_LIFETIME_THRESHOLD: u32 = TOKEN_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// Descriptive token data. Ownership lives only in `TokenKey::TokenOwner`,
+/// see `owner_of`.
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[contracttype]
 pub struct NFTMetadata {
@@ -10,9 +26,48 @@ pub struct NFTMetadata {
     pub description: String,
     pub image_url: String,
     pub level: u32,
+}
+
+/// Metadata as stored before ownership was dropped from it; only read by the
+/// migration.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct LegacyNFTMetadata {
+    pub name: String,
+    pub description: String,
+    pub image_url: String,
+    pub level: u32,
     pub owner: Address
 }
 
+/// A single-token approval, valid up to and including `expiration_ledger`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[contracttype]
+pub struct TokenApproval {
+    pub operator: Address,
+    pub expiration_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct OperatorApprovalKey {
+    pub owner: Address,
+    pub operator: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct OwnerTokenKey {
+    pub owner: Address,
+    pub index: u32,
+}
+
+/// Storage layout:
+///
+/// * instance: `ADMIN_KEY`, `PENDING_ADMIN_KEY`, `COUNTER_KEY` (ids minted),
+///   `SUPPLY_KEY` (live tokens)
+/// * temporary: `Approval(token_id)`, live until its expiration ledger
+/// * persistent: every other `TokenKey` entry
 #[contracttype]
 #[derive(Clone)]
 pub enum TokenKey {
@@ -20,4 +75,12 @@ pub enum TokenKey {
     TokenMetadata(u32),
     TokenUri(u32),
     UserTokenBalance(Address),
+    Updater(Address),
+    Approval(u32),
+    OperatorApproval(OperatorApprovalKey),
+    // Enumeration: dense positions 0..balance per owner and 0..supply overall
+    OwnerToken(OwnerTokenKey),
+    OwnerTokenIndex(u32),
+    AllTokens(u32),
+    AllTokensIndex(u32),
 }
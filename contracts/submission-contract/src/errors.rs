@@ -0,0 +1,38 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SubmissionError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    SubmissionNotFound = 3,
+    AlreadyApproved = 4,
+    AlreadyRejected = 5,
+    NoPendingAdmin = 6,
+    InvalidRewardAmount = 7,
+    NotVerifier = 8,
+    AlreadyVoted = 9,
+    SubmissionNotPending = 10,
+    VotingNotConfigured = 11,
+    InvalidVotingConfig = 12,
+    NotAuthorized = 13,
+    SubmissionNotRejected = 14,
+    AppealWindowClosed = 15,
+    AlreadyAppealed = 16,
+    SubmissionNotDisputed = 17,
+    InvalidLocation = 18,
+    InvalidWeight = 19,
+    IdenticalPhotoHashes = 20,
+    CooldownActive = 21,
+    TooManyPendingSubmissions = 22,
+    InvalidSubmissionLimits = 23,
+    InvalidStakeAmount = 24,
+    DuplicateEvidence = 25,
+    InvalidReviewDeadline = 26,
+    SubmissionNotOverdue = 27,
+    SubmissionUnderReview = 28,
+    TooManyAmendments = 29,
+    InvalidLevelThresholds = 30,
+    AppealWindowOpen = 31,
+    InsufficientRewardPool = 32,
+}
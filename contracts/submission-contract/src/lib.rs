@@ -1,2 +1,1127 @@
 #![no_std]
-pub mod logic;
+mod errors;
+mod nft;
+mod storage;
+mod types;
+
+use crate::{errors::SubmissionError, nft::NftClient, types::*};
+use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, Map, String, Symbol, Vec};
+use storage::SubmissionStorageLayer;
+
+#[contract]
+pub struct SubmissionContract;
+
+#[contractimpl]
+impl SubmissionContract {
+    pub fn init(env: Env, admin: Address) -> Result<(), SubmissionError> {
+        if SubmissionStorageLayer::has_admin(&env) {
+            return Err(SubmissionError::AlreadyInitialized);
+        }
+        // The admin must accept control of the contract
+        admin.require_auth();
+
+        // Set the admin address
+        SubmissionStorageLayer::set_admin(&env, &admin);
+        // Initialize submission count
+        SubmissionStorageLayer::set_submission_count(&env, 0);
+        SubmissionStorageLayer::extend_instance(&env);
+
+        Ok(())
+    }
+
+    pub fn propose_admin(env: Env, new_admin: Address) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        // Replaces any earlier nomination; nothing changes until it is accepted
+        SubmissionStorageLayer::set_pending_admin(&env, &new_admin);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "AdminProposed"), admin), new_admin);
+
+        Ok(())
+    }
+
+    pub fn accept_admin(env: Env) -> Result<(), SubmissionError> {
+        let new_admin = SubmissionStorageLayer::retrieve_pending_admin(&env)
+            .ok_or(SubmissionError::NoPendingAdmin)?;
+        new_admin.require_auth();
+        SubmissionStorageLayer::extend_instance(&env);
+
+        let old_admin =
+            SubmissionStorageLayer::retrieve_admin(&env).ok_or(SubmissionError::NotInitialized)?;
+        SubmissionStorageLayer::set_admin(&env, &new_admin);
+        SubmissionStorageLayer::remove_pending_admin(&env);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "AdminAccepted"), old_admin), new_admin);
+
+        Ok(())
+    }
+
+    pub fn get_admin(env: Env) -> Result<Address, SubmissionError> {
+        SubmissionStorageLayer::retrieve_admin(&env).ok_or(SubmissionError::NotInitialized)
+    }
+
+    /// Sets the reward schedule applied on every approval (see `RewardConfig`).
+    /// Rewards are transferred from this contract's own balance, so the
+    /// contract must be funded with `token` before approvals can pay out.
+    pub fn set_reward_config(
+        env: Env,
+        token: Address,
+        base_amounts: Map<WasteCategory, i128>,
+        per_kg: i128,
+        cap: i128,
+    ) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        if cap <= 0 || per_kg < 0 || base_amounts.values().iter().any(|base| base < 0) {
+            return Err(SubmissionError::InvalidRewardAmount);
+        }
+
+        let config = RewardConfig {
+            token,
+            base_amounts,
+            per_kg,
+            cap,
+        };
+        SubmissionStorageLayer::set_reward_config(&env, &config);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "RewardConfigSet"), admin), config);
+
+        Ok(())
+    }
+
+    pub fn get_reward_config(env: Env) -> Option<RewardConfig> {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_reward_config(&env)
+    }
+
+    /// Sets the minimum time between two submissions from one address and how
+    /// many of its submissions may be pending at once.
+    pub fn set_submission_limits(
+        env: Env,
+        cooldown: u64,
+        max_pending: u32,
+    ) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        if max_pending == 0 {
+            return Err(SubmissionError::InvalidSubmissionLimits);
+        }
+
+        let limits = SubmissionLimits {
+            cooldown,
+            max_pending,
+        };
+        SubmissionStorageLayer::set_submission_limits(&env, &limits);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "SubmissionLimitsSet"), admin), limits);
+
+        Ok(())
+    }
+
+    pub fn get_submission_limits(env: Env) -> Option<SubmissionLimits> {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_submission_limits(&env)
+    }
+
+    /// Requires a deposit of `amount` of `token` with every new submission.
+    /// Submissions created before the change keep the stake they were taken with.
+    pub fn set_stake_config(
+        env: Env,
+        token: Address,
+        amount: i128,
+        treasury: Address,
+    ) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        if amount <= 0 {
+            return Err(SubmissionError::InvalidStakeAmount);
+        }
+
+        let config = StakeConfig {
+            token,
+            amount,
+            treasury,
+        };
+        SubmissionStorageLayer::set_stake_config(&env, &config);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "StakeConfigSet"), admin), config);
+
+        Ok(())
+    }
+
+    /// Stops requiring a stake. Stakes already held are still settled as usual.
+    pub fn remove_stake_config(env: Env) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        SubmissionStorageLayer::remove_stake_config(&env);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "StakeConfigRemoved"), admin), ());
+
+        Ok(())
+    }
+
+    pub fn get_stake_config(env: Env) -> Option<StakeConfig> {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_stake_config(&env)
+    }
+
+    /// Sends the stake of a rejected submission to the treasury once its
+    /// appeal window has closed without an appeal. Anyone can call this.
+    pub fn slash_stake(env: Env, submission_id: u64) -> Result<(), SubmissionError> {
+        SubmissionStorageLayer::extend_instance(&env);
+        let mut submission = SubmissionStorageLayer::get_submission(&env, submission_id)
+            .ok_or(SubmissionError::SubmissionNotFound)?;
+
+        if submission.status != SubmissionStatus::Rejected {
+            return Err(SubmissionError::SubmissionNotRejected);
+        }
+        if submission.appeal == AppealRecord::NotFiled
+            && env.ledger().timestamp() <= Self::appeal_deadline(&env, &submission)
+        {
+            return Err(SubmissionError::AppealWindowOpen);
+        }
+
+        Self::settle_stake(&env, &mut submission, false);
+        SubmissionStorageLayer::set_submission(&env, &submission);
+
+        Ok(())
+    }
+
+    /// Sets how long a submission may stay pending before anyone can expire it.
+    pub fn set_review_deadline(env: Env, seconds: u64) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        if seconds == 0 {
+            return Err(SubmissionError::InvalidReviewDeadline);
+        }
+
+        SubmissionStorageLayer::set_review_deadline(&env, seconds);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "ReviewDeadlineSet"), admin), seconds);
+
+        Ok(())
+    }
+
+    pub fn get_review_deadline(env: Env) -> Option<u64> {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_review_deadline(&env)
+    }
+
+    /// Moves a submission that has been pending past the review deadline to
+    /// `Expired`. Callable by anyone. The stake is refunded and the evidence
+    /// photos are released, so the submitter can submit them again.
+    pub fn expire_submission(env: Env, submission_id: u64) -> Result<(), SubmissionError> {
+        SubmissionStorageLayer::extend_instance(&env);
+        let mut submission = SubmissionStorageLayer::get_submission(&env, submission_id)
+            .ok_or(SubmissionError::SubmissionNotFound)?;
+        if submission.status != SubmissionStatus::Pending {
+            return Err(SubmissionError::SubmissionNotPending);
+        }
+
+        // Without a deadline nothing is ever overdue
+        let deadline = SubmissionStorageLayer::get_review_deadline(&env)
+            .ok_or(SubmissionError::SubmissionNotOverdue)?;
+        let now = env.ledger().timestamp();
+        if now < submission.timestamp.saturating_add(deadline) {
+            return Err(SubmissionError::SubmissionNotOverdue);
+        }
+
+        Self::close_submission(&env, &mut submission, SubmissionStatus::Expired);
+        SubmissionStorageLayer::set_submission(&env, &submission);
+
+        // Emit event
+        env.events().publish(
+            (
+                Symbol::new(&env, "SubmissionExpired"),
+                submission.submitter.clone(),
+            ),
+            submission_id,
+        );
+
+        Ok(())
+    }
+
+    /// Lets the submitter cancel a pending submission. The stake is refunded
+    /// and the evidence photos are released.
+    pub fn withdraw_submission(env: Env, submission_id: u64) -> Result<(), SubmissionError> {
+        let mut submission = Self::load_own_pending_submission(&env, submission_id)?;
+
+        Self::close_submission(&env, &mut submission, SubmissionStatus::Withdrawn);
+        SubmissionStorageLayer::set_submission(&env, &submission);
+
+        // Emit event
+        env.events().publish(
+            (
+                Symbol::new(&env, "SubmissionWithdrawn"),
+                submission.submitter.clone(),
+            ),
+            submission_id,
+        );
+
+        Ok(())
+    }
+
+    /// Lets the submitter replace the evidence of a pending submission that no
+    /// verifier has voted on yet. The replaced evidence is kept as a version
+    /// (see `get_evidence_history`); at most `MAX_AMENDMENTS` are allowed.
+    pub fn amend_submission(
+        env: Env,
+        submission_id: u64,
+        new_evidence: Evidence,
+    ) -> Result<u32, SubmissionError> {
+        let mut submission = Self::load_own_pending_submission(&env, submission_id)?;
+
+        if submission.approve_votes + submission.reject_votes > 0 {
+            return Err(SubmissionError::SubmissionUnderReview);
+        }
+        if submission.version >= MAX_AMENDMENTS {
+            return Err(SubmissionError::TooManyAmendments);
+        }
+        Self::validate_evidence(&new_evidence)?;
+        // Keeping one of the current photos is fine
+        Self::ensure_evidence_unused(&env, &new_evidence, Some(submission_id))?;
+
+        SubmissionStorageLayer::set_evidence_version(
+            &env,
+            submission_id,
+            submission.version,
+            &submission.evidence,
+        );
+        Self::release_evidence(&env, &submission.evidence);
+        Self::index_evidence(&env, &new_evidence, submission_id);
+        submission.evidence = new_evidence;
+        submission.version += 1;
+        SubmissionStorageLayer::set_submission(&env, &submission);
+
+        // Emit event
+        env.events().publish(
+            (
+                Symbol::new(&env, "SubmissionAmended"),
+                submission.submitter.clone(),
+            ),
+            (submission_id, submission.version),
+        );
+
+        Ok(submission.version)
+    }
+
+    /// Returns every version of a submission's evidence, oldest first. The last
+    /// entry is the current evidence.
+    pub fn get_evidence_history(
+        env: Env,
+        submission_id: u64,
+    ) -> Result<Vec<Evidence>, SubmissionError> {
+        SubmissionStorageLayer::extend_instance(&env);
+        let submission = SubmissionStorageLayer::get_submission(&env, submission_id)
+            .ok_or(SubmissionError::SubmissionNotFound)?;
+
+        let mut history = Vec::new(&env);
+        for version in 0..submission.version {
+            if let Some(evidence) =
+                SubmissionStorageLayer::get_evidence_version(&env, submission_id, version)
+            {
+                history.push_back(evidence);
+            }
+        }
+        history.push_back(submission.evidence);
+        Ok(history)
+    }
+
+    /// Configures the impact NFT awarded for approved cleanups. The NFT admin
+    /// must register this contract as an updater of `nft_contract`.
+    pub fn set_impact_nft_config(
+        env: Env,
+        nft_contract: Address,
+        name: String,
+        description: String,
+        image_url: String,
+        thresholds: Vec<u32>,
+    ) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        // Thresholds must be positive and strictly ascending
+        if thresholds.is_empty() {
+            return Err(SubmissionError::InvalidLevelThresholds);
+        }
+        let mut previous = 0;
+        for threshold in thresholds.iter() {
+            if threshold <= previous {
+                return Err(SubmissionError::InvalidLevelThresholds);
+            }
+            previous = threshold;
+        }
+
+        let config = ImpactNftConfig {
+            nft_contract,
+            name,
+            description,
+            image_url,
+            thresholds,
+        };
+        SubmissionStorageLayer::set_impact_nft_config(&env, &config);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "ImpactNftConfigSet"), admin), config);
+
+        Ok(())
+    }
+
+    pub fn get_impact_nft_config(env: Env) -> Option<ImpactNftConfig> {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_impact_nft_config(&env)
+    }
+
+    pub fn get_approved_count(env: Env, submitter: Address) -> u32 {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_approved_count(&env, &submitter)
+    }
+
+    pub fn get_impact_nft(env: Env, submitter: Address) -> Option<ImpactNft> {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_impact_nft(&env, &submitter)
+    }
+
+    pub fn get_pending_count(env: Env, submitter: Address) -> u32 {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_pending_count(&env, &submitter)
+    }
+
+    pub fn create_submission(
+        env: Env,
+        submitter: Address,
+        evidence: Evidence,
+    ) -> Result<u64, SubmissionError> {
+        submitter.require_auth();
+        SubmissionStorageLayer::extend_instance(&env);
+        Self::validate_evidence(&evidence)?;
+        Self::ensure_evidence_unused(&env, &evidence, None)?;
+        let timestamp = env.ledger().timestamp();
+
+        let pending = SubmissionStorageLayer::get_pending_count(&env, &submitter);
+        if let Some(limits) = SubmissionStorageLayer::get_submission_limits(&env) {
+            let last = SubmissionStorageLayer::get_last_submission_at(&env, &submitter);
+            if last.is_some_and(|last| timestamp < last.saturating_add(limits.cooldown)) {
+                return Err(SubmissionError::CooldownActive);
+            }
+            if pending >= limits.max_pending {
+                return Err(SubmissionError::TooManyPendingSubmissions);
+            }
+        }
+        SubmissionStorageLayer::set_last_submission_at(&env, &submitter, timestamp);
+        SubmissionStorageLayer::set_pending_count(&env, &submitter, pending + 1);
+
+        // Take the deposit, if one is required
+        let stake = match SubmissionStorageLayer::get_stake_config(&env) {
+            Some(config) => {
+                token::TokenClient::new(&env, &config.token).transfer(
+                    &submitter,
+                    &env.current_contract_address(),
+                    &config.amount,
+                );
+                let held = SubmissionStorageLayer::get_held_stake(&env, &config.token);
+                SubmissionStorageLayer::set_held_stake(&env, &config.token, held + config.amount);
+                StakeRecord::Held(config)
+            }
+            None => StakeRecord::NotRequired,
+        };
+
+        // Retrieve and increment submission count
+        let submission_id = SubmissionStorageLayer::get_submission_count(&env);
+        SubmissionStorageLayer::set_submission_count(&env, submission_id + 1);
+
+        // Create submission
+        let submission = Submission {
+            id: submission_id,
+            submitter: submitter.clone(),
+            evidence,
+            timestamp,
+            status: SubmissionStatus::Pending,
+            approver: None,
+            processed_timestamp: None,
+            rewarded: false,
+            reward_amount: 0,
+            stake,
+            approve_votes: 0,
+            reject_votes: 0,
+            appeal: AppealRecord::NotFiled,
+            version: 0,
+        };
+
+        // Store submission and index it
+        SubmissionStorageLayer::set_submission(&env, &submission);
+        SubmissionStorageLayer::add_to_status_index(&env, &submission.status, submission_id);
+        SubmissionStorageLayer::add_to_submitter_index(&env, &submitter, submission_id);
+        Self::index_evidence(&env, &submission.evidence, submission_id);
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(&env, "SubmissionCreated"), submitter),
+            submission_id,
+        );
+
+        Ok(submission_id)
+    }
+
+    pub fn approve_submission(env: Env, submission_id: u64) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        let mut submission = SubmissionStorageLayer::get_submission(&env, submission_id)
+            .ok_or(SubmissionError::SubmissionNotFound)?;
+
+        Self::ensure_undecided(&submission)?;
+
+        Self::finalize_approval(&env, &mut submission, &admin)?;
+        SubmissionStorageLayer::set_submission(&env, &submission);
+
+        Ok(())
+    }
+
+    pub fn reject_submission(env: Env, submission_id: u64) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        let mut submission = SubmissionStorageLayer::get_submission(&env, submission_id)
+            .ok_or(SubmissionError::SubmissionNotFound)?;
+
+        Self::ensure_undecided(&submission)?;
+
+        Self::finalize_rejection(&env, &mut submission, &admin);
+        SubmissionStorageLayer::set_submission(&env, &submission);
+
+        Ok(())
+    }
+
+    pub fn set_voting_config(
+        env: Env,
+        quorum: u32,
+        threshold_bps: u32,
+    ) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        if quorum == 0 || threshold_bps == 0 || threshold_bps > MAX_THRESHOLD_BPS {
+            return Err(SubmissionError::InvalidVotingConfig);
+        }
+
+        let config = VotingConfig {
+            quorum,
+            threshold_bps,
+        };
+        SubmissionStorageLayer::set_voting_config(&env, &config);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "VotingConfigSet"), admin), config);
+
+        Ok(())
+    }
+
+    pub fn get_voting_config(env: Env) -> Option<VotingConfig> {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_voting_config(&env)
+    }
+
+    pub fn add_verifier(env: Env, verifier: Address) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        SubmissionStorageLayer::set_verifier(&env, &verifier, true);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "VerifierAdded"), admin), verifier);
+
+        Ok(())
+    }
+
+    /// Votes already cast by `verifier` keep counting towards their submissions.
+    pub fn remove_verifier(env: Env, verifier: Address) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        SubmissionStorageLayer::set_verifier(&env, &verifier, false);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "VerifierRemoved"), admin), verifier);
+
+        Ok(())
+    }
+
+    pub fn is_verifier(env: Env, verifier: Address) -> bool {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::is_verifier(&env, &verifier)
+    }
+
+    /// Records `verifier`'s vote on a pending submission and decides it once
+    /// the configured quorum is reached.
+    pub fn vote(
+        env: Env,
+        verifier: Address,
+        submission_id: u64,
+        approve: bool,
+    ) -> Result<SubmissionStatus, SubmissionError> {
+        verifier.require_auth();
+        SubmissionStorageLayer::extend_instance(&env);
+
+        if !SubmissionStorageLayer::is_verifier(&env, &verifier) {
+            return Err(SubmissionError::NotVerifier);
+        }
+        let config = SubmissionStorageLayer::get_voting_config(&env)
+            .ok_or(SubmissionError::VotingNotConfigured)?;
+
+        let mut submission = SubmissionStorageLayer::get_submission(&env, submission_id)
+            .ok_or(SubmissionError::SubmissionNotFound)?;
+        // Verifiers cannot review their own cleanups
+        if verifier == submission.submitter {
+            return Err(SubmissionError::NotAuthorized);
+        }
+        if submission.status != SubmissionStatus::Pending {
+            return Err(SubmissionError::SubmissionNotPending);
+        }
+        if SubmissionStorageLayer::get_vote(&env, submission_id, &verifier).is_some() {
+            return Err(SubmissionError::AlreadyVoted);
+        }
+
+        SubmissionStorageLayer::set_vote(&env, submission_id, &verifier, approve);
+        if approve {
+            submission.approve_votes += 1;
+        } else {
+            submission.reject_votes += 1;
+        }
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(&env, "VoteCast"), verifier.clone()),
+            (submission_id, approve),
+        );
+
+        let total_votes = submission.approve_votes + submission.reject_votes;
+        if total_votes >= config.quorum {
+            let approve_bps = u64::from(submission.approve_votes) * u64::from(MAX_THRESHOLD_BPS);
+            if approve_bps >= u64::from(config.threshold_bps) * u64::from(total_votes) {
+                Self::finalize_approval(&env, &mut submission, &verifier)?;
+            } else {
+                Self::finalize_rejection(&env, &mut submission, &verifier);
+            }
+        }
+
+        SubmissionStorageLayer::set_submission(&env, &submission);
+
+        Ok(submission.status)
+    }
+
+    pub fn set_appeal_window(env: Env, seconds: u64) -> Result<(), SubmissionError> {
+        let admin = Self::require_admin(&env)?;
+
+        SubmissionStorageLayer::set_appeal_window(&env, seconds);
+
+        // Emit event
+        env.events()
+            .publish((Symbol::new(&env, "AppealWindowSet"), admin), seconds);
+
+        Ok(())
+    }
+
+    pub fn get_appeal_window(env: Env) -> u64 {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_appeal_window(&env)
+    }
+
+    /// Lets the submitter contest a rejection once, within the appeal window
+    /// counted from `processed_timestamp`. Moves the submission to `Disputed`.
+    pub fn appeal(
+        env: Env,
+        submission_id: u64,
+        evidence_uri: BytesN<32>,
+    ) -> Result<(), SubmissionError> {
+        SubmissionStorageLayer::extend_instance(&env);
+        let mut submission = SubmissionStorageLayer::get_submission(&env, submission_id)
+            .ok_or(SubmissionError::SubmissionNotFound)?;
+        submission.submitter.require_auth();
+
+        if submission.status != SubmissionStatus::Rejected {
+            return Err(SubmissionError::SubmissionNotRejected);
+        }
+        if submission.appeal != AppealRecord::NotFiled {
+            return Err(SubmissionError::AlreadyAppealed);
+        }
+
+        let now = env.ledger().timestamp();
+        if now > Self::appeal_deadline(&env, &submission) {
+            return Err(SubmissionError::AppealWindowClosed);
+        }
+
+        Self::set_status(&env, &mut submission, SubmissionStatus::Disputed);
+        submission.appeal = AppealRecord::Filed(Appeal {
+            evidence_uri,
+            timestamp: now,
+            resolver: None,
+            upheld: None,
+        });
+        SubmissionStorageLayer::set_submission(&env, &submission);
+
+        // Emit event
+        env.events().publish(
+            (
+                Symbol::new(&env, "SubmissionAppealed"),
+                submission.submitter.clone(),
+            ),
+            submission_id,
+        );
+
+        Ok(())
+    }
+
+    /// Settles a disputed submission. `resolver` must be the admin or a
+    /// registered verifier; upholding the appeal approves and rewards it.
+    /// `processed_timestamp` is updated to the time of resolution.
+    pub fn resolve_dispute(
+        env: Env,
+        resolver: Address,
+        submission_id: u64,
+        uphold: bool,
+    ) -> Result<SubmissionStatus, SubmissionError> {
+        resolver.require_auth();
+        SubmissionStorageLayer::extend_instance(&env);
+
+        let is_admin = SubmissionStorageLayer::retrieve_admin(&env) == Some(resolver.clone());
+        if !is_admin && !SubmissionStorageLayer::is_verifier(&env, &resolver) {
+            return Err(SubmissionError::NotAuthorized);
+        }
+
+        let mut submission = SubmissionStorageLayer::get_submission(&env, submission_id)
+            .ok_or(SubmissionError::SubmissionNotFound)?;
+        // Verifiers cannot settle appeals on their own cleanups
+        if resolver == submission.submitter {
+            return Err(SubmissionError::NotAuthorized);
+        }
+        if submission.status != SubmissionStatus::Disputed {
+            return Err(SubmissionError::SubmissionNotDisputed);
+        }
+
+        if let AppealRecord::Filed(appeal) = &mut submission.appeal {
+            appeal.resolver = Some(resolver.clone());
+            appeal.upheld = Some(uphold);
+        }
+
+        if uphold {
+            Self::finalize_approval(&env, &mut submission, &resolver)?;
+        } else {
+            Self::finalize_rejection(&env, &mut submission, &resolver);
+            // A dismissed appeal is final, so the stake need not wait any longer
+            Self::settle_stake(&env, &mut submission, false);
+        }
+        SubmissionStorageLayer::set_submission(&env, &submission);
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(&env, "DisputeResolved"), resolver),
+            (submission_id, uphold),
+        );
+
+        Ok(submission.status)
+    }
+
+    /// Returns `Some(approve)` if `verifier` has voted on the submission.
+    pub fn get_vote(env: Env, submission_id: u64, verifier: Address) -> Option<bool> {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_vote(&env, submission_id, &verifier)
+    }
+
+    pub fn get_submission(env: Env, submission_id: u64) -> Result<Submission, SubmissionError> {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_submission(&env, submission_id)
+            .ok_or(SubmissionError::SubmissionNotFound)
+    }
+
+    pub fn get_submission_count(env: Env) -> u64 {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_submission_count(&env)
+    }
+
+    /// Returns the id of the submission that used `hash` as a before or after
+    /// photo, if any.
+    pub fn find_by_evidence(env: Env, hash: BytesN<32>) -> Option<u64> {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_evidence_owner(&env, &hash)
+    }
+
+    /// Returns up to `limit` submissions currently in `status`, starting at
+    /// index position `start`. Positions are reused as submissions change
+    /// status, so the order is not stable between calls that modify the index.
+    /// `limit` is capped at `MAX_PAGE_SIZE` to stay within the per-transaction
+    /// ledger read limit.
+    pub fn list_submissions(
+        env: Env,
+        status: SubmissionStatus,
+        start: u64,
+        limit: u32,
+    ) -> Vec<Submission> {
+        SubmissionStorageLayer::extend_instance(&env);
+        let count = SubmissionStorageLayer::get_status_count(&env, &status);
+        Self::collect_page(&env, start, limit, count, |position| {
+            SubmissionStorageLayer::get_status_entry(&env, &status, position)
+        })
+    }
+
+    /// Returns up to `limit` of `submitter`'s submissions in creation order,
+    /// starting at `start`. `limit` is capped at `MAX_PAGE_SIZE` to stay within
+    /// the per-transaction ledger read limit.
+    pub fn list_by_submitter(
+        env: Env,
+        submitter: Address,
+        start: u64,
+        limit: u32,
+    ) -> Vec<Submission> {
+        SubmissionStorageLayer::extend_instance(&env);
+        let count = SubmissionStorageLayer::get_submitter_count(&env, &submitter);
+        Self::collect_page(&env, start, limit, count, |position| {
+            SubmissionStorageLayer::get_submitter_entry(&env, &submitter, position)
+        })
+    }
+
+    pub fn get_status_count(env: Env, status: SubmissionStatus) -> u64 {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_status_count(&env, &status)
+    }
+
+    pub fn get_submitter_count(env: Env, submitter: Address) -> u64 {
+        SubmissionStorageLayer::extend_instance(&env);
+        SubmissionStorageLayer::get_submitter_count(&env, &submitter)
+    }
+}
+
+impl SubmissionContract {
+    /// Loads the admin and requires its authorization for the current call.
+    fn require_admin(env: &Env) -> Result<Address, SubmissionError> {
+        let admin =
+            SubmissionStorageLayer::retrieve_admin(env).ok_or(SubmissionError::NotInitialized)?;
+        admin.require_auth();
+        SubmissionStorageLayer::extend_instance(env);
+        Ok(admin)
+    }
+
+    /// Only pending submissions can be decided by the admin: a decision is
+    /// final unless the submitter appeals it, and appeals go through
+    /// `resolve_dispute`.
+    fn ensure_undecided(submission: &Submission) -> Result<(), SubmissionError> {
+        match submission.status {
+            SubmissionStatus::Pending => Ok(()),
+            SubmissionStatus::Approved => Err(SubmissionError::AlreadyApproved),
+            SubmissionStatus::Rejected => Err(SubmissionError::AlreadyRejected),
+            // Appeals are settled by `resolve_dispute`; closed submissions have
+            // released their stake and evidence
+            SubmissionStatus::Disputed
+            | SubmissionStatus::Expired
+            | SubmissionStatus::Withdrawn => Err(SubmissionError::SubmissionNotPending),
+        }
+    }
+
+    /// Moves `submission` to `status`, keeping the status index in step.
+    fn set_status(env: &Env, submission: &mut Submission, status: SubmissionStatus) {
+        if submission.status == SubmissionStatus::Pending {
+            let pending = SubmissionStorageLayer::get_pending_count(env, &submission.submitter);
+            SubmissionStorageLayer::set_pending_count(
+                env,
+                &submission.submitter,
+                pending.saturating_sub(1),
+            );
+        }
+        SubmissionStorageLayer::remove_from_status_index(env, &submission.status, submission.id);
+        SubmissionStorageLayer::add_to_status_index(env, &status, submission.id);
+        submission.status = status;
+    }
+
+    /// Loads a pending submission and requires its submitter's authorization.
+    fn load_own_pending_submission(
+        env: &Env,
+        submission_id: u64,
+    ) -> Result<Submission, SubmissionError> {
+        SubmissionStorageLayer::extend_instance(env);
+        let submission = SubmissionStorageLayer::get_submission(env, submission_id)
+            .ok_or(SubmissionError::SubmissionNotFound)?;
+        submission.submitter.require_auth();
+
+        if submission.status != SubmissionStatus::Pending {
+            return Err(SubmissionError::SubmissionNotPending);
+        }
+        Ok(submission)
+    }
+
+    /// Takes a pending submission out of review without a decision: the stake
+    /// is refunded and its evidence can be used again.
+    fn close_submission(env: &Env, submission: &mut Submission, status: SubmissionStatus) {
+        Self::set_status(env, submission, status);
+        submission.processed_timestamp = Some(env.ledger().timestamp());
+        Self::settle_stake(env, submission, true);
+        Self::release_evidence(env, &submission.evidence);
+    }
+
+    /// Fails if either photo is already claimed by a submission other than
+    /// `owner`.
+    fn ensure_evidence_unused(
+        env: &Env,
+        evidence: &Evidence,
+        owner: Option<u64>,
+    ) -> Result<(), SubmissionError> {
+        for hash in [&evidence.before_hash, &evidence.after_hash] {
+            if let Some(existing) = SubmissionStorageLayer::get_evidence_owner(env, hash) {
+                if Some(existing) != owner {
+                    return Err(SubmissionError::DuplicateEvidence);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn index_evidence(env: &Env, evidence: &Evidence, submission_id: u64) {
+        SubmissionStorageLayer::set_evidence_owner(env, &evidence.before_hash, submission_id);
+        SubmissionStorageLayer::set_evidence_owner(env, &evidence.after_hash, submission_id);
+    }
+
+    fn release_evidence(env: &Env, evidence: &Evidence) {
+        SubmissionStorageLayer::remove_evidence_owner(env, &evidence.before_hash);
+        SubmissionStorageLayer::remove_evidence_owner(env, &evidence.after_hash);
+    }
+
+    /// Rejects evidence that cannot describe a real cleanup.
+    fn validate_evidence(evidence: &Evidence) -> Result<(), SubmissionError> {
+        if evidence.before_hash == evidence.after_hash {
+            return Err(SubmissionError::IdenticalPhotoHashes);
+        }
+        if evidence.latitude.unsigned_abs() > MAX_LATITUDE_MICRODEG as u32
+            || evidence.longitude.unsigned_abs() > MAX_LONGITUDE_MICRODEG as u32
+        {
+            return Err(SubmissionError::InvalidLocation);
+        }
+        if evidence.weight_grams == 0 || evidence.weight_grams > MAX_WEIGHT_GRAMS {
+            return Err(SubmissionError::InvalidWeight);
+        }
+        Ok(())
+    }
+
+    /// Loads the submissions at index positions `start..start + limit`.
+    fn collect_page(
+        env: &Env,
+        start: u64,
+        limit: u32,
+        count: u64,
+        entry: impl Fn(u64) -> Option<u64>,
+    ) -> Vec<Submission> {
+        let end = count.min(start.saturating_add(u64::from(limit.min(MAX_PAGE_SIZE))));
+        let mut page = Vec::new(env);
+        for position in start..end {
+            if let Some(submission) =
+                entry(position).and_then(|id| SubmissionStorageLayer::get_submission(env, id))
+            {
+                page.push_back(submission);
+            }
+        }
+        page
+    }
+
+    /// Marks `submission` approved by `approver`, pays the reward and counts
+    /// it towards the submitter's impact. `Approved` is final (see
+    /// `ensure_undecided`), so this runs once per submission.
+    /// The caller is responsible for persisting the submission.
+    fn finalize_approval(
+        env: &Env,
+        submission: &mut Submission,
+        approver: &Address,
+    ) -> Result<(), SubmissionError> {
+        Self::set_status(env, submission, SubmissionStatus::Approved);
+        submission.approver = Some(approver.clone());
+        submission.processed_timestamp = Some(env.ledger().timestamp());
+        submission.reward_amount = Self::pay_reward(env, submission)?;
+        submission.rewarded = submission.reward_amount > 0;
+        Self::settle_stake(env, submission, true);
+        Self::record_impact(env, &submission.submitter);
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(env, "SubmissionApproved"), approver.clone()),
+            submission.id,
+        );
+
+        Ok(())
+    }
+
+    /// Marks `submission` rejected by `approver`. The stake stays held until
+    /// the appeal is dismissed or the appeal window closes (`slash_stake`).
+    /// The caller is responsible for persisting the submission.
+    fn finalize_rejection(env: &Env, submission: &mut Submission, approver: &Address) {
+        Self::set_status(env, submission, SubmissionStatus::Rejected);
+        submission.approver = Some(approver.clone());
+        submission.processed_timestamp = Some(env.ledger().timestamp());
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(env, "SubmissionRejected"), approver.clone()),
+            submission.id,
+        );
+    }
+
+    /// Pays the scheduled reward to the submitter, returning the amount paid.
+    /// Rewards come only from the part of the balance not held as stakes; a
+    /// short pool fails the whole approval.
+    fn pay_reward(env: &Env, submission: &Submission) -> Result<i128, SubmissionError> {
+        let Some(config) = SubmissionStorageLayer::get_reward_config(env) else {
+            return Ok(0);
+        };
+        let amount = Self::compute_reward(&config, &submission.evidence);
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        let token_client = token::TokenClient::new(env, &config.token);
+        let pool = token_client.balance(&env.current_contract_address())
+            - SubmissionStorageLayer::get_held_stake(env, &config.token);
+        if pool < amount {
+            return Err(SubmissionError::InsufficientRewardPool);
+        }
+        token_client.transfer(
+            &env.current_contract_address(),
+            &submission.submitter,
+            &amount,
+        );
+
+        // Emit event
+        env.events().publish(
+            (
+                Symbol::new(env, "SubmissionRewarded"),
+                submission.submitter.clone(),
+            ),
+            (submission.id, amount),
+        );
+
+        Ok(amount)
+    }
+
+    /// Counts an approved cleanup for `submitter` and mints or levels up their
+    /// impact NFT when the count reaches the next threshold. NFT calls never
+    /// revert the approval: a burned NFT is replaced by a fresh one, and a
+    /// failed mint is retried on the submitter's next approval.
+    fn record_impact(env: &Env, submitter: &Address) {
+        let approved = SubmissionStorageLayer::get_approved_count(env, submitter) + 1;
+        SubmissionStorageLayer::set_approved_count(env, submitter, approved);
+
+        let Some(config) = SubmissionStorageLayer::get_impact_nft_config(env) else {
+            return;
+        };
+        let level = config
+            .thresholds
+            .iter()
+            .filter(|threshold| approved >= *threshold)
+            .count() as u32;
+        let nft_client = NftClient::new(env, &config.nft_contract);
+        let current = SubmissionStorageLayer::get_impact_nft(env, submitter);
+        if level <= current.as_ref().map_or(0, |nft| nft.level) {
+            return;
+        }
+
+        if let Some(mut nft) = current {
+            let old_level = nft.level;
+            let leveled_up = nft_client
+                .try_set_level(&env.current_contract_address(), &nft.token_id, &level)
+                .is_ok_and(|result| result.is_ok());
+            if leveled_up {
+                nft.level = level;
+                SubmissionStorageLayer::set_impact_nft(env, submitter, &nft);
+
+                // Emit event
+                env.events().publish(
+                    (Symbol::new(env, "ImpactLevelUp"), submitter.clone()),
+                    (nft.token_id, old_level, level),
+                );
+                return;
+            }
+            // The holder burned the NFT: award a fresh one at the new level
+        }
+
+        let Ok(Ok(token_id)) = nft_client.try_mint(
+            &env.current_contract_address(),
+            submitter,
+            &config.name,
+            &config.description,
+            &config.image_url,
+            &level,
+        ) else {
+            return;
+        };
+        SubmissionStorageLayer::set_impact_nft(env, submitter, &ImpactNft { token_id, level });
+
+        // Emit event
+        env.events().publish(
+            (Symbol::new(env, "ImpactNftMinted"), submitter.clone()),
+            (token_id, level),
+        );
+    }
+
+    /// Last moment a rejected submission can be appealed.
+    fn appeal_deadline(env: &Env, submission: &Submission) -> u64 {
+        submission
+            .processed_timestamp
+            .unwrap_or(submission.timestamp)
+            .saturating_add(SubmissionStorageLayer::get_appeal_window(env))
+    }
+
+    /// Returns a held stake to the submitter, or sends it to the treasury.
+    fn settle_stake(env: &Env, submission: &mut Submission, refund: bool) {
+        let StakeRecord::Held(stake) = submission.stake.clone() else {
+            return;
+        };
+        let recipient = if refund {
+            submission.submitter.clone()
+        } else {
+            stake.treasury.clone()
+        };
+
+        token::TokenClient::new(env, &stake.token).transfer(
+            &env.current_contract_address(),
+            &recipient,
+            &stake.amount,
+        );
+        let held = SubmissionStorageLayer::get_held_stake(env, &stake.token);
+        SubmissionStorageLayer::set_held_stake(env, &stake.token, held - stake.amount);
+
+        // Emit event
+        let topic = if refund {
+            "StakeRefunded"
+        } else {
+            "StakeSlashed"
+        };
+        env.events().publish(
+            (Symbol::new(env, topic), recipient),
+            (submission.id, stake.amount),
+        );
+
+        submission.stake = if refund {
+            StakeRecord::Refunded(stake)
+        } else {
+            StakeRecord::Slashed(stake)
+        };
+    }
+
+    fn compute_reward(config: &RewardConfig, evidence: &Evidence) -> i128 {
+        let base = config.base_amounts.get(evidence.category).unwrap_or(0);
+        let by_weight = config
+            .per_kg
+            .saturating_mul(i128::from(evidence.weight_grams))
+            / 1_000;
+        base.saturating_add(by_weight).min(config.cap)
+    }
+}
+
+mod test;
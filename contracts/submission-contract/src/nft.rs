@@ -0,0 +1,19 @@
+use soroban_sdk::{contractclient, Address, Env, String};
+
+/// The parts of nft-contract used to award impact NFTs. This contract must be
+/// registered as an updater of the NFT contract for these calls to be authorized.
+#[allow(dead_code)] // only the generated client is used
+#[contractclient(name = "NftClient")]
+pub trait NftInterface {
+    fn mint(
+        env: Env,
+        caller: Address,
+        to: Address,
+        name: String,
+        description: String,
+        image_url: String,
+        level: u32,
+    ) -> u32;
+
+    fn set_level(env: Env, caller: Address, token_id: u32, level: u32);
+}
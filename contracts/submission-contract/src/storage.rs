@@ -0,0 +1,370 @@
+use crate::types::{
+    DataKey, Evidence, EvidenceVersionKey, ImpactNft, ImpactNftConfig, RewardConfig, StakeConfig,
+    StatusIndexKey, Submission, SubmissionLimits, SubmissionStatus, SubmitterIndexKey, VoteKey,
+    VotingConfig, DEFAULT_APPEAL_WINDOW, INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD,
+    SUBMISSION_BUMP_AMOUNT, SUBMISSION_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{Address, BytesN, Env, IntoVal, TryFromVal, Val};
+
+pub struct SubmissionStorageLayer;
+
+impl SubmissionStorageLayer {
+    pub fn extend_instance(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    pub fn has_admin(env: &Env) -> bool {
+        env.storage().instance().has(&DataKey::Admin)
+    }
+
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    pub fn retrieve_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    pub fn set_pending_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::PendingAdmin, admin);
+    }
+
+    pub fn retrieve_pending_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    pub fn remove_pending_admin(env: &Env) {
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+    }
+
+    pub fn set_submission_count(env: &Env, count: u64) {
+        env.storage()
+            .instance()
+            .set(&DataKey::SubmissionCount, &count);
+    }
+
+    pub fn get_submission_count(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::SubmissionCount)
+            .unwrap_or(0)
+    }
+
+    pub fn set_reward_config(env: &Env, config: &RewardConfig) {
+        env.storage().instance().set(&DataKey::RewardConfig, config);
+    }
+
+    pub fn get_reward_config(env: &Env) -> Option<RewardConfig> {
+        env.storage().instance().get(&DataKey::RewardConfig)
+    }
+
+    pub fn set_voting_config(env: &Env, config: &VotingConfig) {
+        env.storage().instance().set(&DataKey::VotingConfig, config);
+    }
+
+    pub fn get_voting_config(env: &Env) -> Option<VotingConfig> {
+        env.storage().instance().get(&DataKey::VotingConfig)
+    }
+
+    pub fn set_appeal_window(env: &Env, seconds: u64) {
+        env.storage()
+            .instance()
+            .set(&DataKey::AppealWindow, &seconds);
+    }
+
+    pub fn get_appeal_window(env: &Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::AppealWindow)
+            .unwrap_or(DEFAULT_APPEAL_WINDOW)
+    }
+
+    pub fn set_submission_limits(env: &Env, limits: &SubmissionLimits) {
+        env.storage()
+            .instance()
+            .set(&DataKey::SubmissionLimits, limits);
+    }
+
+    pub fn get_submission_limits(env: &Env) -> Option<SubmissionLimits> {
+        env.storage().instance().get(&DataKey::SubmissionLimits)
+    }
+
+    pub fn set_stake_config(env: &Env, config: &StakeConfig) {
+        env.storage().instance().set(&DataKey::StakeConfig, config);
+    }
+
+    pub fn get_stake_config(env: &Env) -> Option<StakeConfig> {
+        env.storage().instance().get(&DataKey::StakeConfig)
+    }
+
+    pub fn remove_stake_config(env: &Env) {
+        env.storage().instance().remove(&DataKey::StakeConfig);
+    }
+
+    pub fn set_review_deadline(env: &Env, seconds: u64) {
+        env.storage()
+            .instance()
+            .set(&DataKey::ReviewDeadline, &seconds);
+    }
+
+    pub fn get_review_deadline(env: &Env) -> Option<u64> {
+        env.storage().instance().get(&DataKey::ReviewDeadline)
+    }
+
+    pub fn set_impact_nft_config(env: &Env, config: &ImpactNftConfig) {
+        env.storage()
+            .instance()
+            .set(&DataKey::ImpactNftConfig, config);
+    }
+
+    pub fn get_impact_nft_config(env: &Env) -> Option<ImpactNftConfig> {
+        env.storage().instance().get(&DataKey::ImpactNftConfig)
+    }
+
+    //==== Per-submitter activity, read by the anti-spam limits ====//
+    pub fn set_last_submission_at(env: &Env, submitter: &Address, timestamp: u64) {
+        Self::set_persistent(
+            env,
+            &DataKey::LastSubmissionAt(submitter.clone()),
+            &timestamp,
+        );
+    }
+
+    pub fn get_last_submission_at(env: &Env, submitter: &Address) -> Option<u64> {
+        Self::get_persistent(env, &DataKey::LastSubmissionAt(submitter.clone()))
+    }
+
+    pub fn set_pending_count(env: &Env, submitter: &Address, count: u32) {
+        Self::set_persistent(env, &DataKey::PendingCount(submitter.clone()), &count);
+    }
+
+    pub fn get_pending_count(env: &Env, submitter: &Address) -> u32 {
+        Self::get_persistent(env, &DataKey::PendingCount(submitter.clone())).unwrap_or(0)
+    }
+
+    //==== Per-submitter impact: approved cleanups and the NFT they earned ====//
+    pub fn set_approved_count(env: &Env, submitter: &Address, count: u32) {
+        Self::set_persistent(env, &DataKey::ApprovedCount(submitter.clone()), &count);
+    }
+
+    pub fn get_approved_count(env: &Env, submitter: &Address) -> u32 {
+        Self::get_persistent(env, &DataKey::ApprovedCount(submitter.clone())).unwrap_or(0)
+    }
+
+    pub fn set_impact_nft(env: &Env, submitter: &Address, nft: &ImpactNft) {
+        Self::set_persistent(env, &DataKey::ImpactNft(submitter.clone()), nft);
+    }
+
+    pub fn get_impact_nft(env: &Env, submitter: &Address) -> Option<ImpactNft> {
+        Self::get_persistent(env, &DataKey::ImpactNft(submitter.clone()))
+    }
+
+    //==== Stakes in escrow per token, kept out of the reward pool ====//
+    pub fn set_held_stake(env: &Env, token: &Address, amount: i128) {
+        Self::set_persistent(env, &DataKey::HeldStake(token.clone()), &amount);
+    }
+
+    pub fn get_held_stake(env: &Env, token: &Address) -> i128 {
+        Self::get_persistent(env, &DataKey::HeldStake(token.clone())).unwrap_or(0)
+    }
+
+    //==== Evidence index: photo hash to the submission that first used it ====//
+    pub fn set_evidence_owner(env: &Env, hash: &BytesN<32>, submission_id: u64) {
+        Self::set_persistent(env, &DataKey::EvidenceHash(hash.clone()), &submission_id);
+    }
+
+    pub fn get_evidence_owner(env: &Env, hash: &BytesN<32>) -> Option<u64> {
+        Self::get_persistent(env, &DataKey::EvidenceHash(hash.clone()))
+    }
+
+    pub fn remove_evidence_owner(env: &Env, hash: &BytesN<32>) {
+        env.storage()
+            .persistent()
+            .remove(&DataKey::EvidenceHash(hash.clone()));
+    }
+
+    //==== Superseded evidence, one entry per amended version ====//
+    pub fn set_evidence_version(env: &Env, submission_id: u64, version: u32, evidence: &Evidence) {
+        Self::set_persistent(
+            env,
+            &DataKey::EvidenceVersion(EvidenceVersionKey {
+                submission_id,
+                version,
+            }),
+            evidence,
+        );
+    }
+
+    pub fn get_evidence_version(env: &Env, submission_id: u64, version: u32) -> Option<Evidence> {
+        Self::get_persistent(
+            env,
+            &DataKey::EvidenceVersion(EvidenceVersionKey {
+                submission_id,
+                version,
+            }),
+        )
+    }
+
+    //==== Verifier registry: absent means not a verifier ====//
+    pub fn set_verifier(env: &Env, verifier: &Address, active: bool) {
+        let key = DataKey::Verifier(verifier.clone());
+        if active {
+            Self::set_persistent(env, &key, &true);
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    pub fn is_verifier(env: &Env, verifier: &Address) -> bool {
+        Self::get_persistent::<bool>(env, &DataKey::Verifier(verifier.clone())).is_some()
+    }
+
+    //==== Votes: one record per (submission, verifier), value is the approve flag ====//
+    pub fn set_vote(env: &Env, submission_id: u64, verifier: &Address, approve: bool) {
+        Self::set_persistent(
+            env,
+            &DataKey::Vote(VoteKey {
+                submission_id,
+                verifier: verifier.clone(),
+            }),
+            &approve,
+        );
+    }
+
+    pub fn get_vote(env: &Env, submission_id: u64, verifier: &Address) -> Option<bool> {
+        Self::get_persistent(
+            env,
+            &DataKey::Vote(VoteKey {
+                submission_id,
+                verifier: verifier.clone(),
+            }),
+        )
+    }
+
+    //==== Submissions: every read and write bumps the entry's TTL ====//
+    pub fn set_submission(env: &Env, submission: &Submission) {
+        Self::set_persistent(env, &DataKey::Submission(submission.id), submission);
+    }
+
+    pub fn get_submission(env: &Env, submission_id: u64) -> Option<Submission> {
+        Self::get_persistent(env, &DataKey::Submission(submission_id))
+    }
+
+    //==== Status index: swap-remove keeps positions dense, so order is not stable ====//
+    pub fn get_status_count(env: &Env, status: &SubmissionStatus) -> u64 {
+        Self::get_counter(env, &DataKey::StatusCount(status.clone()))
+    }
+
+    pub fn get_status_entry(env: &Env, status: &SubmissionStatus, position: u64) -> Option<u64> {
+        Self::get_persistent(
+            env,
+            &DataKey::StatusIndex(StatusIndexKey {
+                status: status.clone(),
+                position,
+            }),
+        )
+    }
+
+    pub fn add_to_status_index(env: &Env, status: &SubmissionStatus, submission_id: u64) {
+        let position = Self::get_status_count(env, status);
+        Self::set_status_entry(env, status, position, submission_id);
+        Self::set_persistent(env, &DataKey::StatusCount(status.clone()), &(position + 1));
+    }
+
+    /// Removes `submission_id` from `status`'s index by moving the last entry
+    /// into its slot.
+    pub fn remove_from_status_index(env: &Env, status: &SubmissionStatus, submission_id: u64) {
+        let Some(position) =
+            Self::get_persistent::<u64>(env, &DataKey::StatusPosition(submission_id))
+        else {
+            return;
+        };
+        let last = Self::get_status_count(env, status) - 1;
+
+        if position != last {
+            let moved_id = Self::get_status_entry(env, status, last).unwrap();
+            Self::set_status_entry(env, status, position, moved_id);
+        }
+        env.storage()
+            .persistent()
+            .remove(&DataKey::StatusIndex(StatusIndexKey {
+                status: status.clone(),
+                position: last,
+            }));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::StatusPosition(submission_id));
+        Self::set_persistent(env, &DataKey::StatusCount(status.clone()), &last);
+    }
+
+    fn set_status_entry(env: &Env, status: &SubmissionStatus, position: u64, submission_id: u64) {
+        Self::set_persistent(
+            env,
+            &DataKey::StatusIndex(StatusIndexKey {
+                status: status.clone(),
+                position,
+            }),
+            &submission_id,
+        );
+        Self::set_persistent(env, &DataKey::StatusPosition(submission_id), &position);
+    }
+
+    //==== Submitter index: append-only, in creation order ====//
+    pub fn get_submitter_count(env: &Env, submitter: &Address) -> u64 {
+        Self::get_counter(env, &DataKey::SubmitterCount(submitter.clone()))
+    }
+
+    pub fn get_submitter_entry(env: &Env, submitter: &Address, position: u64) -> Option<u64> {
+        Self::get_persistent(
+            env,
+            &DataKey::SubmitterIndex(SubmitterIndexKey {
+                submitter: submitter.clone(),
+                position,
+            }),
+        )
+    }
+
+    pub fn add_to_submitter_index(env: &Env, submitter: &Address, submission_id: u64) {
+        let position = Self::get_submitter_count(env, submitter);
+        Self::set_persistent(
+            env,
+            &DataKey::SubmitterIndex(SubmitterIndexKey {
+                submitter: submitter.clone(),
+                position,
+            }),
+            &submission_id,
+        );
+        Self::set_persistent(
+            env,
+            &DataKey::SubmitterCount(submitter.clone()),
+            &(position + 1),
+        );
+    }
+
+    fn get_counter(env: &Env, key: &DataKey) -> u64 {
+        Self::get_persistent(env, key).unwrap_or(0)
+    }
+
+    fn get_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+        let value = env.storage().persistent().get(key);
+        if value.is_some() {
+            Self::extend_persistent(env, key);
+        }
+        value
+    }
+
+    fn set_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+        env.storage().persistent().set(key, value);
+        Self::extend_persistent(env, key);
+    }
+
+    fn extend_persistent(env: &Env, key: &DataKey) {
+        env.storage().persistent().extend_ttl(
+            key,
+            SUBMISSION_LIFETIME_THRESHOLD,
+            SUBMISSION_BUMP_AMOUNT,
+        );
+    }
+}
@@ -1,146 +1,1382 @@
-#![no_std]
+#![cfg(test)]
 
+use crate::{
+    errors::SubmissionError,
+    types::{
+        Appeal, AppealRecord, Evidence, ImpactNft, StakeRecord, Submission, SubmissionStatus,
+        WasteCategory, MAX_AMENDMENTS, MAX_PAGE_SIZE, MAX_WEIGHT_GRAMS,
+    },
+    SubmissionContract, SubmissionContractClient,
+};
+use nft_contract::{NFTContract, NFTContractClient};
 use soroban_sdk::{
-    contract, contractimpl, contracttype, Address, Env, Symbol, Vec, Map, BytesN, Panic,
+    map,
+    testutils::{Address as _, Ledger},
+    token::{StellarAssetClient, TokenClient},
+    vec, Address, BytesN, Env, Map, String, Vec,
 };
 
-#[contracttype]
-#[derive(Clone)]
-pub enum SubmissionStatus {
-    Pending,
-    Approved,
-    Rejected,
-}
-
-#[contracttype]
-#[derive(Clone)]
-pub struct Submission {
-    pub id: u64,
-    pub submitter: Address,
-    pub data_uri: BytesN<32>, // Assuming IPFS hash
-    pub timestamp: u64,
-    pub status: SubmissionStatus,
-    pub approver: Option<Address>,
-    pub processed_timestamp: Option<u64>,
-    pub rewarded: bool,
-}
-
-#[contract]
-pub struct SubmissionContract;
-
-#[contractimpl]
-impl SubmissionContract {
-    pub fn init(env: Env, admin: Address) {
-        // Set the admin address
-        env.storage().set(&Symbol::short("admin"), &admin);
-        // Initialize submission count
-        env.storage().set(&Symbol::short("submission_count"), &0u64);
+fn setup_contract(env: &Env) -> (SubmissionContractClient<'_>, Address) {
+    let contract_id = env.register(SubmissionContract, ());
+    let client = SubmissionContractClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    client.mock_all_auths().init(&admin);
+
+    (client, admin)
+}
+
+/// Registers a reward token and funds the submission contract with `pool`.
+fn setup_reward_token<'a>(
+    env: &Env,
+    client: &SubmissionContractClient,
+    pool: i128,
+) -> TokenClient<'a> {
+    let issuer = Address::generate(env);
+    let token = env.register_stellar_asset_contract_v2(issuer);
+    StellarAssetClient::new(env, &token.address()).mint(&client.address, &pool);
+    TokenClient::new(env, &token.address())
+}
+
+/// Pays `amount` for any approved submission regardless of its evidence.
+fn set_flat_reward(env: &Env, client: &SubmissionContractClient, token: &Address, amount: i128) {
+    let mut base_amounts = Map::new(env);
+    for category in [
+        WasteCategory::Plastic,
+        WasteCategory::Glass,
+        WasteCategory::Metal,
+        WasteCategory::Paper,
+        WasteCategory::Organic,
+        WasteCategory::Electronic,
+        WasteCategory::Hazardous,
+        WasteCategory::Mixed,
+    ] {
+        base_amounts.set(category, amount);
     }
+    client.set_reward_config(token, &base_amounts, &0, &amount);
+}
 
-    pub fn create_submission(env: Env, data_uri: BytesN<32>) -> u64 {
-        let submitter = env.invoker();
-        let timestamp = env.ledger().timestamp();
-
-        // Retrieve and increment submission count
-        let mut count: u64 = env.storage().get(&Symbol::short("submission_count")).unwrap_or(0);
-        let submission_id = count;
-        count += 1;
-        env.storage().set(&Symbol::short("submission_count"), &count);
-
-        // Create submission
-        let submission = Submission {
-            id: submission_id,
-            submitter: submitter.clone(),
-            data_uri,
-            timestamp,
-            status: SubmissionStatus::Pending,
-            approver: None,
-            processed_timestamp: None,
-            rewarded: false,
-        };
-
-        // Store submission
-        let key = Symbol::short(format!("submission_{}", submission_id).as_str());
-        env.storage().set(&key, &submission);
-
-        // Emit event
-        env.events().publish(
-            (Symbol::short("SubmissionCreated"), submitter),
-            submission_id,
-        );
+fn hash(env: &Env, seed: u8) -> BytesN<32> {
+    BytesN::from_array(env, &[seed; 32])
+}
 
-        submission_id
+/// A valid report whose photo hashes are derived from `seed`.
+fn evidence(env: &Env, seed: u8) -> Evidence {
+    Evidence {
+        before_hash: hash(env, seed),
+        after_hash: hash(env, seed.wrapping_add(128)),
+        latitude: 52_370_216,
+        longitude: 4_895_168,
+        category: WasteCategory::Plastic,
+        weight_grams: 2_500,
     }
+}
 
-    pub fn approve_submission(env: Env, submission_id: u64) {
-        let caller = env.invoker();
-        let admin: Address = env.storage().get(&Symbol::short("admin")).expect("Admin not set");
-        if caller != admin {
-            panic!("Only admin can approve submissions");
-        }
+#[test]
+#[should_panic(expected = "Unauthorized function call for address")]
+fn test_init_without_admin_auth_should_fail() {
+    let env = Env::default();
+    let contract_id = env.register(SubmissionContract, ());
+    let client = SubmissionContractClient::new(&env, &contract_id);
 
-        let key = Symbol::short(format!("submission_{}", submission_id).as_str());
-        let mut submission: Submission = env
-            .storage()
-            .get(&key)
-            .expect("Submission not found");
+    client.init(&Address::generate(&env));
+}
 
-        if submission.status == SubmissionStatus::Approved {
-            panic!("Submission already approved");
-        }
+#[test]
+fn test_init_twice_should_fail() {
+    let env = Env::default();
+    let (client, admin) = setup_contract(&env);
+
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(
+        client.try_init(&admin),
+        Err(Ok(SubmissionError::AlreadyInitialized))
+    );
+}
 
-        submission.status = SubmissionStatus::Approved;
-        submission.approver = Some(caller.clone());
-        submission.processed_timestamp = Some(env.ledger().timestamp());
-        submission.rewarded = true;
+#[test]
+fn test_create_submission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let user = Address::generate(&env);
 
-        env.storage().set(&key, &submission);
+    env.ledger().set_timestamp(1_000);
+    let first = client.create_submission(&user, &evidence(&env, 1));
+    assert_eq!(env.auths()[0].0, user);
+    let second = client.create_submission(&user, &evidence(&env, 2));
 
-        // Emit event
-        env.events().publish(
-            (Symbol::short("SubmissionApproved"), caller),
-            submission_id,
-        );
+    assert_eq!(first, 0);
+    assert_eq!(second, 1);
+    assert_eq!(client.get_submission_count(), 2);
+
+    let submission = client.get_submission(&first);
+    assert_eq!(submission.submitter, user);
+    assert_eq!(submission.evidence, evidence(&env, 1));
+    assert_eq!(submission.timestamp, 1_000);
+    assert_eq!(submission.status, SubmissionStatus::Pending);
+    assert_eq!(submission.approver, None);
+    assert!(!submission.rewarded);
+}
+
+#[test]
+fn test_create_submission_with_invalid_evidence_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    let mut same_photos = evidence(&env, 1);
+    same_photos.after_hash = same_photos.before_hash.clone();
+    assert_eq!(
+        client.try_create_submission(&user, &same_photos),
+        Err(Ok(SubmissionError::IdenticalPhotoHashes))
+    );
+
+    let mut off_map = evidence(&env, 1);
+    off_map.latitude = -90_000_001;
+    assert_eq!(
+        client.try_create_submission(&user, &off_map),
+        Err(Ok(SubmissionError::InvalidLocation))
+    );
+    off_map.latitude = 0;
+    off_map.longitude = 180_000_001;
+    assert_eq!(
+        client.try_create_submission(&user, &off_map),
+        Err(Ok(SubmissionError::InvalidLocation))
+    );
+
+    let mut weightless = evidence(&env, 1);
+    weightless.weight_grams = 0;
+    assert_eq!(
+        client.try_create_submission(&user, &weightless),
+        Err(Ok(SubmissionError::InvalidWeight))
+    );
+    weightless.weight_grams = MAX_WEIGHT_GRAMS + 1;
+    assert_eq!(
+        client.try_create_submission(&user, &weightless),
+        Err(Ok(SubmissionError::InvalidWeight))
+    );
+
+    // Boundary values are accepted
+    let mut edge = evidence(&env, 1);
+    edge.latitude = 90_000_000;
+    edge.longitude = -180_000_000;
+    edge.weight_grams = MAX_WEIGHT_GRAMS;
+    edge.category = WasteCategory::Hazardous;
+    let id = client.create_submission(&user, &edge);
+    assert_eq!(client.get_submission(&id).evidence, edge);
+    assert_eq!(client.get_submission_count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call for address")]
+fn test_create_submission_without_auth_should_fail() {
+    let env = Env::default();
+    let (client, _admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    client.create_submission(&user, &evidence(&env, 1));
+}
+
+#[test]
+fn test_approve_submission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    env.ledger().set_timestamp(2_000);
+    client.approve_submission(&id);
+    assert_eq!(env.auths()[0].0, admin);
+
+    let submission = client.get_submission(&id);
+    assert_eq!(submission.status, SubmissionStatus::Approved);
+    assert_eq!(submission.approver, Some(admin));
+    assert_eq!(submission.processed_timestamp, Some(2_000));
+    // No reward token configured, so nothing was paid
+    assert!(!submission.rewarded);
+
+    assert_eq!(
+        client.try_approve_submission(&id),
+        Err(Ok(SubmissionError::AlreadyApproved))
+    );
+}
+
+#[test]
+fn test_reject_submission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    client.reject_submission(&id);
+
+    let submission = client.get_submission(&id);
+    assert_eq!(submission.status, SubmissionStatus::Rejected);
+    assert_eq!(submission.approver, Some(admin));
+    assert!(!submission.rewarded);
+
+    assert_eq!(
+        client.try_reject_submission(&id),
+        Err(Ok(SubmissionError::AlreadyRejected))
+    );
+    // Only an appeal can overturn a rejection
+    assert_eq!(
+        client.try_approve_submission(&id),
+        Err(Ok(SubmissionError::AlreadyRejected))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call for address")]
+fn test_approve_submission_by_non_admin_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+
+    env.mock_auths(&[]);
+    client.approve_submission(&id);
+}
+
+#[test]
+fn test_missing_submission_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+
+    let not_found = SubmissionError::SubmissionNotFound;
+    assert_eq!(client.try_get_submission(&7), Err(Ok(not_found)));
+    assert_eq!(client.try_approve_submission(&7), Err(Ok(not_found)));
+    assert_eq!(client.try_reject_submission(&7), Err(Ok(not_found)));
+}
+
+#[test]
+fn test_admin_handover() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let new_admin = Address::generate(&env);
+
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(SubmissionError::NoPendingAdmin))
+    );
+
+    client.propose_admin(&new_admin);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_admin(), admin);
+
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+}
+
+#[test]
+fn test_approval_pays_reward() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 1_000);
+    let user = Address::generate(&env);
+
+    set_flat_reward(&env, &client, &token.address, 250);
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    client.approve_submission(&id);
+
+    let submission = client.get_submission(&id);
+    assert!(submission.rewarded);
+    assert_eq!(submission.reward_amount, 250);
+    assert_eq!(token.balance(&user), 250);
+    assert_eq!(token.balance(&client.address), 750);
+}
+
+#[test]
+fn test_rejection_pays_nothing() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 1_000);
+    let user = Address::generate(&env);
+
+    set_flat_reward(&env, &client, &token.address, 250);
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    client.reject_submission(&id);
+
+    assert!(!client.get_submission(&id).rewarded);
+    assert_eq!(token.balance(&user), 0);
+}
+
+#[test]
+fn test_approved_submission_cannot_be_reopened() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 1_000);
+    let user = Address::generate(&env);
+
+    set_flat_reward(&env, &client, &token.address, 250);
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    env.ledger().set_timestamp(2_000);
+    client.approve_submission(&id);
+    assert_eq!(
+        client.try_reject_submission(&id),
+        Err(Ok(SubmissionError::AlreadyApproved))
+    );
+    assert_eq!(
+        client.try_appeal(&id, &hash(&env, 9)),
+        Err(Ok(SubmissionError::SubmissionNotRejected))
+    );
+
+    let submission = client.get_submission(&id);
+    assert_eq!(submission.status, SubmissionStatus::Approved);
+    assert_eq!(submission.reward_amount, 250);
+    assert_eq!(submission.approver, Some(admin));
+    assert_eq!(submission.processed_timestamp, Some(2_000));
+    assert_eq!(token.balance(&user), 250);
+    assert_eq!(token.balance(&client.address), 750);
+}
+
+#[test]
+fn test_approval_with_empty_pool_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 100);
+    let user = Address::generate(&env);
+
+    set_flat_reward(&env, &client, &token.address, 250);
+    let id = client.create_submission(&user, &evidence(&env, 1));
+
+    // The payout and the approval are one transaction
+    assert_eq!(
+        client.try_approve_submission(&id),
+        Err(Ok(SubmissionError::InsufficientRewardPool))
+    );
+    let submission = client.get_submission(&id);
+    assert_eq!(submission.status, SubmissionStatus::Pending);
+    assert!(!submission.rewarded);
+}
+
+#[test]
+fn test_set_reward_config_with_invalid_amount_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 0);
+
+    let invalid = SubmissionError::InvalidRewardAmount;
+    let no_base = Map::new(&env);
+    let negative_base = map![&env, (WasteCategory::Glass, -1_i128)];
+    assert_eq!(
+        client.try_set_reward_config(&token.address, &no_base, &10, &0),
+        Err(Ok(invalid))
+    );
+    assert_eq!(
+        client.try_set_reward_config(&token.address, &no_base, &-10, &100),
+        Err(Ok(invalid))
+    );
+    assert_eq!(
+        client.try_set_reward_config(&token.address, &negative_base, &10, &100),
+        Err(Ok(invalid))
+    );
+    assert_eq!(client.get_reward_config(), None);
+}
+
+#[test]
+fn test_reward_scales_with_category_and_weight() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 10_000);
+    let user = Address::generate(&env);
+
+    let base_amounts = map![
+        &env,
+        (WasteCategory::Plastic, 100_i128),
+        (WasteCategory::Hazardous, 500_i128)
+    ];
+    client.set_reward_config(&token.address, &base_amounts, &40, &1_000);
+
+    // 100 base + 40 per kg * 2.5 kg
+    let plastic = client.create_submission(&user, &evidence(&env, 1));
+    // No base for glass, weight only: 40 * 0.75 kg
+    let mut small_glass = evidence(&env, 2);
+    small_glass.category = WasteCategory::Glass;
+    small_glass.weight_grams = 750;
+    let glass = client.create_submission(&user, &small_glass);
+    // 500 base + 40 per kg * 20 kg, capped at 1_000
+    let mut heavy_hazard = evidence(&env, 3);
+    heavy_hazard.category = WasteCategory::Hazardous;
+    heavy_hazard.weight_grams = 20_000;
+    let hazard = client.create_submission(&user, &heavy_hazard);
+
+    client.approve_submission(&plastic);
+    client.approve_submission(&glass);
+    client.approve_submission(&hazard);
+
+    assert_eq!(client.get_submission(&plastic).reward_amount, 200);
+    assert_eq!(client.get_submission(&glass).reward_amount, 30);
+    assert_eq!(client.get_submission(&hazard).reward_amount, 1_000);
+    assert_eq!(token.balance(&user), 1_230);
+}
+
+#[test]
+fn test_zero_reward_skips_transfer() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 0);
+    let user = Address::generate(&env);
+
+    client.set_reward_config(&token.address, &Map::new(&env), &0, &100);
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    client.approve_submission(&id);
+
+    let submission = client.get_submission(&id);
+    assert_eq!(submission.status, SubmissionStatus::Approved);
+    assert_eq!(submission.reward_amount, 0);
+    assert!(!submission.rewarded);
+}
+
+fn setup_verifiers(env: &Env, client: &SubmissionContractClient, count: u32) -> Vec<Address> {
+    let mut verifiers = Vec::new(env);
+    for _ in 0..count {
+        let verifier = Address::generate(env);
+        client.add_verifier(&verifier);
+        verifiers.push_back(verifier);
     }
+    verifiers
+}
 
-    pub fn reject_submission(env: Env, submission_id: u64) {
-        let caller = env.invoker();
-        let admin: Address = env.storage().get(&Symbol::short("admin")).expect("Admin not set");
-        if caller != admin {
-            panic!("Only admin can reject submissions");
-        }
+#[test]
+fn test_quorum_approves_submission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 1_000);
+    let verifiers = setup_verifiers(&env, &client, 3);
+    let user = Address::generate(&env);
 
-        let key = Symbol::short(format!("submission_{}", submission_id).as_str());
-        let mut submission: Submission = env
-            .storage()
-            .get(&key)
-            .expect("Submission not found");
+    set_flat_reward(&env, &client, &token.address, 100);
+    // Three votes needed, two thirds of them approving
+    client.set_voting_config(&3, &6_000);
+    let id = client.create_submission(&user, &evidence(&env, 1));
 
-        if submission.status == SubmissionStatus::Rejected {
-            panic!("Submission already rejected");
-        }
+    assert_eq!(
+        client.vote(&verifiers.get(0).unwrap(), &id, &true),
+        SubmissionStatus::Pending
+    );
+    assert_eq!(
+        client.vote(&verifiers.get(1).unwrap(), &id, &false),
+        SubmissionStatus::Pending
+    );
+    assert_eq!(
+        client.vote(&verifiers.get(2).unwrap(), &id, &true),
+        SubmissionStatus::Approved
+    );
+
+    let submission = client.get_submission(&id);
+    assert_eq!(submission.approve_votes, 2);
+    assert_eq!(submission.reject_votes, 1);
+    assert_eq!(submission.approver, verifiers.get(2));
+    assert!(submission.rewarded);
+    assert_eq!(token.balance(&user), 100);
+    assert_eq!(
+        client.get_vote(&id, &verifiers.get(1).unwrap()),
+        Some(false)
+    );
+}
 
-        submission.status = SubmissionStatus::Rejected;
-        submission.approver = Some(caller.clone());
-        submission.processed_timestamp = Some(env.ledger().timestamp());
+#[test]
+fn test_quorum_rejects_submission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let verifiers = setup_verifiers(&env, &client, 2);
+    let user = Address::generate(&env);
 
-        env.storage().set(&key, &submission);
+    client.set_voting_config(&2, &10_000);
+    let id = client.create_submission(&user, &evidence(&env, 1));
 
-        // Emit event
-        env.events().publish(
-            (Symbol::short("SubmissionRejected"), caller),
-            submission_id,
-        );
+    client.vote(&verifiers.get(0).unwrap(), &id, &true);
+    assert_eq!(
+        client.vote(&verifiers.get(1).unwrap(), &id, &false),
+        SubmissionStatus::Rejected
+    );
+
+    // A decided submission accepts no further votes
+    let late = Address::generate(&env);
+    client.add_verifier(&late);
+    assert_eq!(
+        client.try_vote(&late, &id, &true),
+        Err(Ok(SubmissionError::SubmissionNotPending))
+    );
+}
+
+#[test]
+fn test_vote_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let verifiers = setup_verifiers(&env, &client, 1);
+    let verifier = verifiers.get(0).unwrap();
+    let outsider = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    assert_eq!(
+        client.try_vote(&verifier, &id, &true),
+        Err(Ok(SubmissionError::VotingNotConfigured))
+    );
+
+    client.set_voting_config(&2, &5_000);
+    assert_eq!(
+        client.try_vote(&outsider, &id, &true),
+        Err(Ok(SubmissionError::NotVerifier))
+    );
+    assert_eq!(
+        client.try_vote(&verifier, &42, &true),
+        Err(Ok(SubmissionError::SubmissionNotFound))
+    );
+
+    let own = client.create_submission(&verifier, &evidence(&env, 2));
+    assert_eq!(
+        client.try_vote(&verifier, &own, &true),
+        Err(Ok(SubmissionError::NotAuthorized))
+    );
+
+    client.vote(&verifier, &id, &true);
+    assert_eq!(
+        client.try_vote(&verifier, &id, &false),
+        Err(Ok(SubmissionError::AlreadyVoted))
+    );
+
+    client.remove_verifier(&verifier);
+    assert!(!client.is_verifier(&verifier));
+    assert_eq!(client.get_submission(&id).approve_votes, 1);
+}
+
+#[test]
+fn test_set_voting_config_with_invalid_values_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+
+    let invalid = SubmissionError::InvalidVotingConfig;
+    assert_eq!(client.try_set_voting_config(&0, &5_000), Err(Ok(invalid)));
+    assert_eq!(client.try_set_voting_config(&1, &0), Err(Ok(invalid)));
+    assert_eq!(client.try_set_voting_config(&1, &10_001), Err(Ok(invalid)));
+    assert_eq!(client.get_voting_config(), None);
+}
+
+#[test]
+fn test_appeal_and_uphold() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 1_000);
+    let user = Address::generate(&env);
+
+    set_flat_reward(&env, &client, &token.address, 100);
+    client.set_appeal_window(&3_600);
+    let id = client.create_submission(&user, &evidence(&env, 1));
+
+    env.ledger().set_timestamp(10_000);
+    client.reject_submission(&id);
+
+    env.ledger().set_timestamp(13_600);
+    client.appeal(&id, &hash(&env, 9));
+    assert_eq!(env.auths()[0].0, user);
+
+    let submission = client.get_submission(&id);
+    assert_eq!(submission.status, SubmissionStatus::Disputed);
+    // The admin settles appeals through resolve_dispute only
+    let not_pending = Err(Ok(SubmissionError::SubmissionNotPending));
+    assert_eq!(client.try_approve_submission(&id), not_pending);
+    assert_eq!(client.try_reject_submission(&id), not_pending);
+    assert_eq!(
+        submission.appeal,
+        AppealRecord::Filed(Appeal {
+            evidence_uri: hash(&env, 9),
+            timestamp: 13_600,
+            resolver: None,
+            upheld: None,
+        })
+    );
+
+    assert_eq!(
+        client.resolve_dispute(&admin, &id, &true),
+        SubmissionStatus::Approved
+    );
+
+    let submission = client.get_submission(&id);
+    let AppealRecord::Filed(appeal) = submission.appeal else {
+        panic!("appeal should be recorded");
+    };
+    assert_eq!(appeal.resolver, Some(admin));
+    assert_eq!(appeal.upheld, Some(true));
+    assert!(submission.rewarded);
+    assert_eq!(token.balance(&user), 100);
+}
+
+#[test]
+fn test_verifier_can_dismiss_appeal() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let verifier = setup_verifiers(&env, &client, 1).get(0).unwrap();
+    let user = Address::generate(&env);
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    client.reject_submission(&id);
+    client.appeal(&id, &hash(&env, 9));
+
+    assert_eq!(
+        client.resolve_dispute(&verifier, &id, &false),
+        SubmissionStatus::Rejected
+    );
+
+    // Only one appeal per submission
+    assert_eq!(
+        client.try_appeal(&id, &hash(&env, 10)),
+        Err(Ok(SubmissionError::AlreadyAppealed))
+    );
+}
+
+#[test]
+fn test_appeal_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let outsider = Address::generate(&env);
+    let user = Address::generate(&env);
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    assert_eq!(
+        client.try_appeal(&id, &hash(&env, 9)),
+        Err(Ok(SubmissionError::SubmissionNotRejected))
+    );
+
+    client.set_appeal_window(&60);
+    env.ledger().set_timestamp(1_000);
+    client.reject_submission(&id);
+    assert_eq!(
+        client.try_resolve_dispute(&outsider, &id, &true),
+        Err(Ok(SubmissionError::NotAuthorized))
+    );
+
+    env.ledger().set_timestamp(1_061);
+    assert_eq!(
+        client.try_appeal(&id, &hash(&env, 9)),
+        Err(Ok(SubmissionError::AppealWindowClosed))
+    );
+}
+
+#[test]
+fn test_resolve_own_dispute_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let verifier = setup_verifiers(&env, &client, 1).get(0).unwrap();
+
+    let id = client.create_submission(&verifier, &evidence(&env, 1));
+    client.reject_submission(&id);
+    client.appeal(&id, &hash(&env, 9));
+
+    assert_eq!(
+        client.try_resolve_dispute(&verifier, &id, &true),
+        Err(Ok(SubmissionError::NotAuthorized))
+    );
+    assert_eq!(
+        client.get_submission(&id).status,
+        SubmissionStatus::Disputed
+    );
+}
+
+#[test]
+fn test_resolve_undisputed_submission_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+
+    assert_eq!(
+        client.try_resolve_dispute(&admin, &id, &true),
+        Err(Ok(SubmissionError::SubmissionNotDisputed))
+    );
+}
+
+fn ids(env: &Env, submissions: Vec<Submission>) -> Vec<u64> {
+    let mut ids = Vec::new(env);
+    for submission in submissions.iter() {
+        ids.push_back(submission.id);
+    }
+    ids
+}
+
+#[test]
+fn test_list_submissions_follows_status_changes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    for seed in 0..4 {
+        client.create_submission(&user, &evidence(&env, seed));
+    }
+    assert_eq!(
+        ids(
+            &env,
+            client.list_submissions(&SubmissionStatus::Pending, &0, &10)
+        ),
+        vec![&env, 0, 1, 2, 3]
+    );
+
+    client.approve_submission(&1);
+    client.reject_submission(&2);
+    // The last pending entry fills the slot freed by submission 1
+    assert_eq!(
+        ids(
+            &env,
+            client.list_submissions(&SubmissionStatus::Pending, &0, &10)
+        ),
+        vec![&env, 0, 3]
+    );
+    assert_eq!(
+        ids(
+            &env,
+            client.list_submissions(&SubmissionStatus::Approved, &0, &10)
+        ),
+        vec![&env, 1]
+    );
+
+    client.appeal(&2, &hash(&env, 9));
+    assert_eq!(client.get_status_count(&SubmissionStatus::Rejected), 0);
+    assert_eq!(
+        ids(
+            &env,
+            client.list_submissions(&SubmissionStatus::Disputed, &0, &10)
+        ),
+        vec![&env, 2]
+    );
+
+    client.resolve_dispute(&admin, &2, &true);
+    assert_eq!(client.get_status_count(&SubmissionStatus::Disputed), 0);
+    assert_eq!(
+        ids(
+            &env,
+            client.list_submissions(&SubmissionStatus::Approved, &0, &10)
+        ),
+        vec![&env, 1, 2]
+    );
+}
+
+#[test]
+fn test_list_by_submitter_paginates() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+
+    for seed in 0..5 {
+        let submitter = if seed % 2 == 0 { &alice } else { &bob };
+        client.create_submission(submitter, &evidence(&env, seed));
+    }
+    client.reject_submission(&2);
+
+    assert_eq!(client.get_submitter_count(&alice), 3);
+    assert_eq!(
+        ids(&env, client.list_by_submitter(&alice, &0, &2)),
+        vec![&env, 0, 2]
+    );
+    assert_eq!(
+        ids(&env, client.list_by_submitter(&alice, &2, &2)),
+        vec![&env, 4]
+    );
+    assert_eq!(
+        ids(&env, client.list_by_submitter(&bob, &0, &10)),
+        vec![&env, 1, 3]
+    );
+    assert!(client.list_by_submitter(&bob, &5, &10).is_empty());
+
+    // Pages never exceed MAX_PAGE_SIZE
+    for seed in 5..(MAX_PAGE_SIZE as u8 + 10) {
+        client.create_submission(&alice, &evidence(&env, seed));
+    }
+    assert_eq!(
+        client.list_by_submitter(&alice, &0, &u32::MAX).len(),
+        MAX_PAGE_SIZE
+    );
+}
+
+#[test]
+fn test_submission_limits() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_submission_limits(&60, &0),
+        Err(Ok(SubmissionError::InvalidSubmissionLimits))
+    );
+    client.set_submission_limits(&60, &2);
+
+    env.ledger().set_timestamp(1_000);
+    let first = client.create_submission(&user, &evidence(&env, 1));
+    assert_eq!(
+        client.try_create_submission(&user, &evidence(&env, 2)),
+        Err(Ok(SubmissionError::CooldownActive))
+    );
+    // Limits are per address
+    client.create_submission(&other, &evidence(&env, 3));
+
+    env.ledger().set_timestamp(1_060);
+    client.create_submission(&user, &evidence(&env, 4));
+    assert_eq!(client.get_pending_count(&user), 2);
+
+    env.ledger().set_timestamp(2_000);
+    assert_eq!(
+        client.try_create_submission(&user, &evidence(&env, 5)),
+        Err(Ok(SubmissionError::TooManyPendingSubmissions))
+    );
+
+    // Deciding a submission frees a pending slot
+    client.reject_submission(&first);
+    assert_eq!(client.get_pending_count(&user), 1);
+    client.create_submission(&user, &evidence(&env, 5));
+}
+
+#[test]
+fn test_stake_refunded_on_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 0);
+    let treasury = Address::generate(&env);
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &token.address).mint(&user, &100);
+
+    client.set_stake_config(&token.address, &40, &treasury);
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    assert_eq!(token.balance(&user), 60);
+    assert_eq!(token.balance(&client.address), 40);
+
+    client.approve_submission(&id);
+    assert_eq!(token.balance(&user), 100);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(
+        client.get_submission(&id).stake,
+        StakeRecord::Refunded(client.get_stake_config().unwrap())
+    );
+}
+
+#[test]
+fn test_stake_slashed_on_rejection() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 0);
+    let treasury = Address::generate(&env);
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &token.address).mint(&user, &100);
+
+    assert_eq!(
+        client.try_set_stake_config(&token.address, &0, &treasury),
+        Err(Ok(SubmissionError::InvalidStakeAmount))
+    );
+    client.set_stake_config(&token.address, &40, &treasury);
+    let staked = client.create_submission(&user, &evidence(&env, 1));
+
+    // Removing the requirement leaves the held stake in place
+    client.remove_stake_config();
+    let free = client.create_submission(&user, &evidence(&env, 2));
+    assert_eq!(client.get_submission(&free).stake, StakeRecord::NotRequired);
+
+    client.set_appeal_window(&3_600);
+    env.ledger().set_timestamp(1_000);
+    client.reject_submission(&staked);
+    assert!(matches!(
+        client.get_submission(&staked).stake,
+        StakeRecord::Held(_)
+    ));
+
+    // The stake waits for the appeal window to close
+    env.ledger().set_timestamp(4_600);
+    assert_eq!(
+        client.try_slash_stake(&staked),
+        Err(Ok(SubmissionError::AppealWindowOpen))
+    );
+    assert_eq!(
+        client.try_slash_stake(&free),
+        Err(Ok(SubmissionError::SubmissionNotRejected))
+    );
+
+    env.ledger().set_timestamp(4_601);
+    client.slash_stake(&staked);
+    assert_eq!(token.balance(&treasury), 40);
+    assert_eq!(token.balance(&user), 60);
+    assert!(matches!(
+        client.get_submission(&staked).stake,
+        StakeRecord::Slashed(_)
+    ));
+
+    // A stake is settled only once
+    client.slash_stake(&staked);
+    assert_eq!(token.balance(&treasury), 40);
+}
+
+#[test]
+fn test_stake_follows_appeal_outcome() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 0);
+    let treasury = Address::generate(&env);
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &token.address).mint(&user, &100);
+
+    client.set_stake_config(&token.address, &40, &treasury);
+    let upheld = client.create_submission(&user, &evidence(&env, 1));
+    let dismissed = client.create_submission(&user, &evidence(&env, 2));
+    client.reject_submission(&upheld);
+    client.reject_submission(&dismissed);
+    assert_eq!(token.balance(&user), 20);
+
+    client.appeal(&upheld, &hash(&env, 9));
+    client.resolve_dispute(&admin, &upheld, &true);
+    assert_eq!(token.balance(&user), 60);
+    assert!(matches!(
+        client.get_submission(&upheld).stake,
+        StakeRecord::Refunded(_)
+    ));
+
+    client.appeal(&dismissed, &hash(&env, 10));
+    client.resolve_dispute(&admin, &dismissed, &false);
+    assert_eq!(token.balance(&treasury), 40);
+    assert!(matches!(
+        client.get_submission(&dismissed).stake,
+        StakeRecord::Slashed(_)
+    ));
+}
+
+#[test]
+fn test_rewards_never_spend_held_stakes() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 0);
+    let alice = Address::generate(&env);
+    let bob = Address::generate(&env);
+    StellarAssetClient::new(&env, &token.address).mint(&alice, &40);
+    StellarAssetClient::new(&env, &token.address).mint(&bob, &40);
+
+    set_flat_reward(&env, &client, &token.address, 30);
+    client.set_stake_config(&token.address, &40, &Address::generate(&env));
+    let first = client.create_submission(&alice, &evidence(&env, 1));
+    let second = client.create_submission(&bob, &evidence(&env, 2));
+
+    // Bob's stake cannot fund Alice's reward
+    assert_eq!(
+        client.try_approve_submission(&first),
+        Err(Ok(SubmissionError::InsufficientRewardPool))
+    );
+
+    StellarAssetClient::new(&env, &token.address).mint(&client.address, &30);
+    client.approve_submission(&first);
+    assert_eq!(token.balance(&alice), 70);
+    assert_eq!(token.balance(&client.address), 40);
+
+    // Bob's stake is still there to be refunded
+    client.withdraw_submission(&second);
+    assert_eq!(token.balance(&bob), 40);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_create_submission_without_stake_funds_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 0);
+    let user = Address::generate(&env);
+
+    client.set_stake_config(&token.address, &40, &Address::generate(&env));
+
+    assert!(client
+        .try_create_submission(&user, &evidence(&env, 1))
+        .is_err());
+    assert_eq!(client.get_submission_count(), 0);
+    assert_eq!(client.get_pending_count(&user), 0);
+}
+
+#[test]
+fn test_duplicate_evidence_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    assert_eq!(client.find_by_evidence(&hash(&env, 1)), Some(id));
+    assert_eq!(client.find_by_evidence(&hash(&env, 129)), Some(id));
+    assert_eq!(client.find_by_evidence(&hash(&env, 2)), None);
+
+    // Same photos, from anyone
+    assert_eq!(
+        client.try_create_submission(&other, &evidence(&env, 1)),
+        Err(Ok(SubmissionError::DuplicateEvidence))
+    );
+
+    // Reusing either photo in either role is caught
+    let mut swapped = evidence(&env, 2);
+    swapped.before_hash = hash(&env, 129);
+    assert_eq!(
+        client.try_create_submission(&user, &swapped),
+        Err(Ok(SubmissionError::DuplicateEvidence))
+    );
+
+    // Rejected evidence stays indexed
+    client.reject_submission(&id);
+    assert_eq!(
+        client.try_create_submission(&user, &evidence(&env, 1)),
+        Err(Ok(SubmissionError::DuplicateEvidence))
+    );
+    assert_eq!(client.get_submission_count(), 1);
+}
+
+#[test]
+fn test_expire_overdue_submission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 0);
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &token.address).mint(&user, &40);
+
+    client.set_stake_config(&token.address, &40, &Address::generate(&env));
+    client.set_review_deadline(&3_600);
+    env.ledger().set_timestamp(1_000);
+    let id = client.create_submission(&user, &evidence(&env, 1));
+
+    env.ledger().set_timestamp(4_599);
+    assert_eq!(
+        client.try_expire_submission(&id),
+        Err(Ok(SubmissionError::SubmissionNotOverdue))
+    );
+
+    env.ledger().set_timestamp(4_600);
+    env.mock_auths(&[]);
+    client.expire_submission(&id);
+    env.mock_all_auths();
+
+    let submission = client.get_submission(&id);
+    assert_eq!(submission.status, SubmissionStatus::Expired);
+    assert_eq!(submission.processed_timestamp, Some(4_600));
+    assert!(matches!(submission.stake, StakeRecord::Refunded(_)));
+    assert_eq!(token.balance(&user), 40);
+    assert_eq!(client.get_pending_count(&user), 0);
+    assert_eq!(client.get_status_count(&SubmissionStatus::Pending), 0);
+    assert_eq!(client.get_status_count(&SubmissionStatus::Expired), 1);
+
+    // The same photos can be submitted again
+    assert_eq!(client.find_by_evidence(&hash(&env, 1)), None);
+    let resubmitted = client.create_submission(&user, &evidence(&env, 1));
+    assert_eq!(client.find_by_evidence(&hash(&env, 1)), Some(resubmitted));
+
+    let not_pending = SubmissionError::SubmissionNotPending;
+    assert_eq!(client.try_expire_submission(&id), Err(Ok(not_pending)));
+    assert_eq!(client.try_approve_submission(&id), Err(Ok(not_pending)));
+    assert_eq!(client.try_reject_submission(&id), Err(Ok(not_pending)));
+}
+
+#[test]
+fn test_expire_without_deadline_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    assert_eq!(
+        client.try_set_review_deadline(&0),
+        Err(Ok(SubmissionError::InvalidReviewDeadline))
+    );
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    env.ledger().set_timestamp(u64::MAX);
+    assert_eq!(
+        client.try_expire_submission(&id),
+        Err(Ok(SubmissionError::SubmissionNotOverdue))
+    );
+    assert_eq!(client.get_review_deadline(), None);
+}
+
+#[test]
+fn test_withdraw_submission() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let token = setup_reward_token(&env, &client, 0);
+    let user = Address::generate(&env);
+    StellarAssetClient::new(&env, &token.address).mint(&user, &40);
+
+    client.set_stake_config(&token.address, &40, &Address::generate(&env));
+    let id = client.create_submission(&user, &evidence(&env, 1));
+
+    client.withdraw_submission(&id);
+    assert_eq!(env.auths()[0].0, user);
+
+    let submission = client.get_submission(&id);
+    assert_eq!(submission.status, SubmissionStatus::Withdrawn);
+    assert!(matches!(submission.stake, StakeRecord::Refunded(_)));
+    assert_eq!(token.balance(&user), 40);
+    assert_eq!(client.get_pending_count(&user), 0);
+    assert_eq!(client.find_by_evidence(&hash(&env, 1)), None);
+
+    let not_pending = SubmissionError::SubmissionNotPending;
+    assert_eq!(client.try_withdraw_submission(&id), Err(Ok(not_pending)));
+    assert_eq!(client.try_approve_submission(&id), Err(Ok(not_pending)));
+    assert_eq!(
+        client.try_amend_submission(&id, &evidence(&env, 2)),
+        Err(Ok(not_pending))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call for address")]
+fn test_withdraw_by_other_address_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+
+    env.mock_auths(&[]);
+    client.withdraw_submission(&id);
+}
+
+#[test]
+fn test_amend_submission_keeps_history() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let user = Address::generate(&env);
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+
+    // Replace only the after photo
+    let mut better_after = evidence(&env, 1);
+    better_after.after_hash = hash(&env, 7);
+    assert_eq!(client.amend_submission(&id, &better_after), 1);
+    assert_eq!(env.auths()[0].0, user);
+
+    let mut heavier = better_after.clone();
+    heavier.weight_grams = 4_000;
+    assert_eq!(client.amend_submission(&id, &heavier), 2);
+
+    let submission = client.get_submission(&id);
+    assert_eq!(submission.version, 2);
+    assert_eq!(submission.evidence, heavier);
+    assert_eq!(
+        client.get_evidence_history(&id),
+        vec![&env, evidence(&env, 1), better_after, heavier]
+    );
+
+    // The dropped photo is free again, the kept ones still point here
+    assert_eq!(client.find_by_evidence(&hash(&env, 129)), None);
+    assert_eq!(client.find_by_evidence(&hash(&env, 1)), Some(id));
+    assert_eq!(client.find_by_evidence(&hash(&env, 7)), Some(id));
+}
+
+#[test]
+fn test_amend_submission_errors() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let verifier = setup_verifiers(&env, &client, 1).get(0).unwrap();
+    let user = Address::generate(&env);
+
+    let id = client.create_submission(&user, &evidence(&env, 1));
+    let other = client.create_submission(&user, &evidence(&env, 2));
+
+    assert_eq!(
+        client.try_amend_submission(&id, &evidence(&env, 2)),
+        Err(Ok(SubmissionError::DuplicateEvidence))
+    );
+    let mut weightless = evidence(&env, 3);
+    weightless.weight_grams = 0;
+    assert_eq!(
+        client.try_amend_submission(&id, &weightless),
+        Err(Ok(SubmissionError::InvalidWeight))
+    );
+
+    for seed in 10..(10 + MAX_AMENDMENTS as u8) {
+        client.amend_submission(&id, &evidence(&env, seed));
     }
+    assert_eq!(
+        client.try_amend_submission(&id, &evidence(&env, 3)),
+        Err(Ok(SubmissionError::TooManyAmendments))
+    );
 
-    pub fn get_submission(env: Env, submission_id: u64) -> Submission {
-        let key = Symbol::short(format!("submission_{}", submission_id).as_str());
-        env.storage()
-            .get(&key)
-            .expect("Submission not found")
+    // Once verifiers start voting the evidence is frozen
+    client.set_voting_config(&2, &5_000);
+    client.vote(&verifier, &other, &true);
+    assert_eq!(
+        client.try_amend_submission(&other, &evidence(&env, 3)),
+        Err(Ok(SubmissionError::SubmissionUnderReview))
+    );
+}
+
+/// Registers nft-contract with this contract as its admin and configures
+/// impact NFT levels at 1, 3 and 5 approved cleanups.
+fn setup_impact_nft<'a>(env: &Env, client: &SubmissionContractClient) -> NFTContractClient<'a> {
+    let nft_id = env.register(NFTContract, ());
+    let nft = NFTContractClient::new(env, &nft_id);
+    nft.init_admin(&Address::generate(env));
+    nft.add_updater(&client.address);
+
+    client.set_impact_nft_config(
+        &nft_id,
+        &String::from_str(env, "Cleanup Hero"),
+        &String::from_str(env, "Awarded for verified cleanups"),
+        &String::from_str(env, "https://decleanup.net/nft.png"),
+        &vec![env, 1, 3, 5],
+    );
+    nft
+}
+
+#[test]
+fn test_approvals_mint_and_level_up_impact_nft() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_contract(&env);
+    let nft = setup_impact_nft(&env, &client);
+    let user = Address::generate(&env);
+
+    let mut ids = Vec::new(&env);
+    for seed in 0..5 {
+        ids.push_back(client.create_submission(&user, &evidence(&env, seed)));
     }
 
-    pub fn get_submission_count(env: Env) -> u64 {
-        env.storage().get(&Symbol::short("submission_count")).unwrap_or(0)
+    client.approve_submission(&ids.get(0).unwrap());
+    let minted = client.get_impact_nft(&user).unwrap();
+    assert_eq!(
+        minted,
+        ImpactNft {
+            token_id: 0,
+            level: 1
+        }
+    );
+    assert_eq!(nft.owner_of(&minted.token_id), user);
+    assert_eq!(nft.get_metadata(&minted.token_id).level, 1);
+
+    // Each submission counts once
+    assert_eq!(
+        client.try_approve_submission(&ids.get(0).unwrap()),
+        Err(Ok(SubmissionError::AlreadyApproved))
+    );
+    assert_eq!(client.get_approved_count(&user), 1);
+
+    // A rejection does not count
+    client.reject_submission(&ids.get(1).unwrap());
+    client.approve_submission(&ids.get(2).unwrap());
+    assert_eq!(client.get_approved_count(&user), 2);
+    assert_eq!(client.get_impact_nft(&user).unwrap().level, 1);
+
+    client.approve_submission(&ids.get(3).unwrap());
+    assert_eq!(client.get_approved_count(&user), 3);
+    assert_eq!(client.get_impact_nft(&user).unwrap().level, 2);
+    assert_eq!(nft.get_metadata(&minted.token_id).level, 2);
+
+    client.approve_submission(&ids.get(4).unwrap());
+    assert_eq!(client.get_impact_nft(&user).unwrap().level, 2);
+
+    // Overturning the rejection reaches the last threshold, on the same NFT
+    client.appeal(&ids.get(1).unwrap(), &hash(&env, 99));
+    client.resolve_dispute(&admin, &ids.get(1).unwrap(), &true);
+    assert_eq!(client.get_approved_count(&user), 5);
+    assert_eq!(
+        client.get_impact_nft(&user),
+        Some(ImpactNft {
+            token_id: 0,
+            level: 3
+        })
+    );
+    assert_eq!(nft.get_metadata(&0).level, 3);
+}
+
+#[test]
+fn test_impact_nft_failures_do_not_block_approval() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let nft = setup_impact_nft(&env, &client);
+    let user = Address::generate(&env);
+
+    let mut ids = Vec::new(&env);
+    for seed in 0..4 {
+        ids.push_back(client.create_submission(&user, &evidence(&env, seed)));
+    }
+    client.approve_submission(&ids.get(0).unwrap());
+    client.approve_submission(&ids.get(1).unwrap());
+
+    // A burned NFT is replaced when the next level is reached
+    nft.burn(&user, &0);
+    client.approve_submission(&ids.get(2).unwrap());
+    assert_eq!(
+        client.get_impact_nft(&user),
+        Some(ImpactNft {
+            token_id: 1,
+            level: 2
+        })
+    );
+    assert_eq!(nft.owner_of(&1), user);
+
+    // Without the updater role the approval still goes through
+    nft.remove_updater(&client.address);
+    nft.burn(&user, &1);
+    client.set_impact_nft_config(
+        &nft.address,
+        &String::from_str(&env, "Cleanup Hero"),
+        &String::from_str(&env, "Awarded for verified cleanups"),
+        &String::from_str(&env, "https://decleanup.net/nft.png"),
+        &vec![&env, 1, 2, 3, 4],
+    );
+    client.approve_submission(&ids.get(3).unwrap());
+    assert_eq!(
+        client.get_submission(&ids.get(3).unwrap()).status,
+        SubmissionStatus::Approved
+    );
+    assert_eq!(client.get_approved_count(&user), 4);
+    assert_eq!(client.get_impact_nft(&user).unwrap().level, 2);
+}
+
+#[test]
+fn test_set_impact_nft_config_with_invalid_thresholds_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_contract(&env);
+    let nft_contract = Address::generate(&env);
+    let text = String::from_str(&env, "Cleanup Hero");
+
+    let invalid = SubmissionError::InvalidLevelThresholds;
+    for thresholds in [vec![&env], vec![&env, 0, 2], vec![&env, 3, 3]] {
+        assert_eq!(
+            client.try_set_impact_nft_config(&nft_contract, &text, &text, &text, &thresholds),
+            Err(Ok(invalid))
+        );
     }
+    assert_eq!(client.get_impact_nft_config(), None);
 }
@@ -0,0 +1,239 @@
+use soroban_sdk::{contracttype, Address, BytesN, Map, String, Vec};
+
+//==== Every persistent record (submissions, votes, indexes) shares one bump ====//
+pub const DAY_IN_LEDGERS: u32 = 17280;
+pub const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub const SUBMISSION_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const SUBMISSION_LIFETIME_THRESHOLD: u32 = SUBMISSION_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+pub const MAX_THRESHOLD_BPS: u32 = 10_000;
+pub const DEFAULT_APPEAL_WINDOW: u64 = 7 * 24 * 60 * 60; // seconds
+/// A page reads one index entry and one submission per item, plus the
+/// instance and the index count; 15 items keeps that well under the 40
+/// ledger-entry reads a transaction may make.
+pub const MAX_PAGE_SIZE: u32 = 15;
+
+//==== Evidence bounds: coordinates in micro-degrees, weight in grams ====//
+pub const MAX_LATITUDE_MICRODEG: i32 = 90_000_000;
+pub const MAX_LONGITUDE_MICRODEG: i32 = 180_000_000;
+pub const MAX_WEIGHT_GRAMS: u32 = 10_000_000; // 10 tonnes
+pub const MAX_AMENDMENTS: u32 = 10;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SubmissionStatus {
+    Pending,
+    Approved,
+    Rejected,
+    Disputed,
+    Expired,
+    Withdrawn,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum WasteCategory {
+    Plastic,
+    Glass,
+    Metal,
+    Paper,
+    Organic,
+    Electronic,
+    Hazardous,
+    Mixed,
+}
+
+/// What a cleanup report claims. Photo hashes are content hashes (e.g. the
+/// IPFS digest) of the images taken before and after the cleanup.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Evidence {
+    pub before_hash: BytesN<32>,
+    pub after_hash: BytesN<32>,
+    pub latitude: i32,  // micro-degrees
+    pub longitude: i32, // micro-degrees
+    pub category: WasteCategory,
+    pub weight_grams: u32,
+}
+
+/// A submitter's appeal against a rejection, and how it was settled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Appeal {
+    pub evidence_uri: BytesN<32>,
+    pub timestamp: u64,
+    pub resolver: Option<Address>,
+    pub upheld: Option<bool>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AppealRecord {
+    NotFiled,
+    Filed(Appeal),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Submission {
+    pub id: u64,
+    pub submitter: Address,
+    pub evidence: Evidence,
+    pub timestamp: u64,
+    pub status: SubmissionStatus,
+    pub approver: Option<Address>,
+    pub processed_timestamp: Option<u64>,
+    pub rewarded: bool,
+    pub reward_amount: i128,
+    pub stake: StakeRecord,
+    pub approve_votes: u32,
+    pub reject_votes: u32,
+    pub appeal: AppealRecord,
+    pub version: u32, // number of amendments; earlier evidence is kept per version
+}
+
+/// Reward schedule, paid in `token` on every approval from this contract's
+/// balance minus the stakes it holds:
+///
+/// `min(cap, base_amounts[category] + per_kg * weight_grams / 1000)`
+///
+/// Categories missing from `base_amounts` earn no base amount.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardConfig {
+    pub token: Address,
+    pub base_amounts: Map<WasteCategory, i128>,
+    pub per_kg: i128,
+    pub cap: i128,
+}
+
+/// Anti-spam limits applied per submitter by `create_submission`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubmissionLimits {
+    pub cooldown: u64, // seconds between two submissions
+    pub max_pending: u32,
+}
+
+/// Deposit taken from the submitter on `create_submission`, refunded on
+/// approval and sent to `treasury` once a rejection is final.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeConfig {
+    pub token: Address,
+    pub amount: i128,
+    pub treasury: Address,
+}
+
+/// The stake taken for a submission. It stays `Held` while the submission
+/// can still be approved: through review and, after a rejection, until the
+/// appeal is resolved or the appeal window closes. It is settled only once.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StakeRecord {
+    NotRequired,
+    Held(StakeConfig),
+    Refunded(StakeConfig),
+    Slashed(StakeConfig),
+}
+
+/// Impact NFT awarded as a submitter's approved cleanups add up. The level is
+/// the number of `thresholds` (ascending approved counts) reached; the first
+/// level mints the NFT and later ones raise its level.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImpactNftConfig {
+    pub nft_contract: Address,
+    pub name: String,
+    pub description: String,
+    pub image_url: String,
+    pub thresholds: Vec<u32>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImpactNft {
+    pub token_id: u32,
+    pub level: u32,
+}
+
+/// A submission is decided once `quorum` verifiers have voted: it is approved
+/// if at least `threshold_bps` (basis points) of those votes approve it and
+/// rejected otherwise.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VotingConfig {
+    pub quorum: u32,
+    pub threshold_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct VoteKey {
+    pub submission_id: u64,
+    pub verifier: Address,
+}
+
+//==== Query indexes: dense positions 0..len per status and per submitter ====//
+#[contracttype]
+#[derive(Clone)]
+pub struct StatusIndexKey {
+    pub status: SubmissionStatus,
+    pub position: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct SubmitterIndexKey {
+    pub submitter: Address,
+    pub position: u64,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct EvidenceVersionKey {
+    pub submission_id: u64,
+    pub version: u32,
+}
+
+/// Storage layout:
+///
+/// * instance: `Admin`, `PendingAdmin`, `SubmissionCount`, `RewardConfig`,
+///   `VotingConfig`, `AppealWindow`, `SubmissionLimits`, `StakeConfig`,
+///   `ReviewDeadline`, `ImpactNftConfig`
+/// * persistent: `Submission(id)`, `Verifier(address)`, `Vote(id, verifier)`,
+///   `StatusCount(status)`, `StatusIndex(status, position)`, `StatusPosition(id)`,
+///   `SubmitterCount(address)`, `SubmitterIndex(address, position)`,
+///   `LastSubmissionAt(address)`, `PendingCount(address)`, `EvidenceHash(hash)`,
+///   `EvidenceVersion(id, version)`, `ApprovedCount(address)`, `ImpactNft(address)`,
+///   `HeldStake(token)`
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    PendingAdmin,
+    SubmissionCount,
+    Submission(u64),
+    RewardConfig,
+    VotingConfig,
+    AppealWindow,
+    Verifier(Address),
+    Vote(VoteKey),
+    StatusCount(SubmissionStatus),
+    StatusIndex(StatusIndexKey),
+    StatusPosition(u64),
+    SubmitterCount(Address),
+    SubmitterIndex(SubmitterIndexKey),
+    SubmissionLimits,
+    StakeConfig,
+    LastSubmissionAt(Address),
+    PendingCount(Address),
+    EvidenceHash(BytesN<32>),
+    ReviewDeadline,
+    EvidenceVersion(EvidenceVersionKey),
+    ImpactNftConfig,
+    ApprovedCount(Address),
+    ImpactNft(Address),
+    HeldStake(Address),
+}
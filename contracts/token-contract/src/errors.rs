@@ -0,0 +1,16 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum TokenError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    InvalidAmount = 3,
+    InsufficientBalance = 4,
+    InsufficientAllowance = 5,
+    InvalidExpirationLedger = 6,
+    InvalidCap = 7,
+    SupplyCapExceeded = 8,
+    SupplyOverflow = 9,
+    AccountFrozen = 10,
+}
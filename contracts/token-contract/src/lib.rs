@@ -1,3 +1,8 @@
 #![no_std]
+pub mod errors;
+pub mod storage;
 pub mod token_logic;
 pub mod token_trait;
+pub mod types;
+
+mod test;
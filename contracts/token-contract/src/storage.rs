@@ -0,0 +1,148 @@
+use crate::types::{
+    AllowanceDataKey, AllowanceValue, DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+    INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD,
+};
+use soroban_sdk::{Address, Env, String};
+
+pub struct TokenStorageLayer;
+
+impl TokenStorageLayer {
+    pub fn extend_instance(env: &Env) {
+        env.storage()
+            .instance()
+            .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+    }
+
+    pub fn has_admin(env: &Env) -> bool {
+        env.storage().instance().has(&DataKey::Admin)
+    }
+
+    pub fn set_admin(env: &Env, admin: &Address) {
+        env.storage().instance().set(&DataKey::Admin, admin);
+    }
+
+    pub fn retrieve_admin(env: &Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Admin)
+    }
+
+    pub fn set_metadata(env: &Env, decimals: u32, name: &String, symbol: &String) {
+        env.storage().instance().set(&DataKey::Decimals, &decimals);
+        env.storage().instance().set(&DataKey::Name, name);
+        env.storage().instance().set(&DataKey::Symbol, symbol);
+    }
+
+    pub fn get_decimals(env: &Env) -> Option<u32> {
+        env.storage().instance().get(&DataKey::Decimals)
+    }
+
+    pub fn get_name(env: &Env) -> Option<String> {
+        env.storage().instance().get(&DataKey::Name)
+    }
+
+    pub fn get_symbol(env: &Env) -> Option<String> {
+        env.storage().instance().get(&DataKey::Symbol)
+    }
+
+    pub fn set_total_supply(env: &Env, supply: i128) {
+        env.storage().instance().set(&DataKey::TotalSupply, &supply);
+    }
+
+    pub fn get_total_supply(env: &Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TotalSupply)
+            .unwrap_or(0)
+    }
+
+    pub fn set_cap(env: &Env, cap: i128) {
+        env.storage().instance().set(&DataKey::Cap, &cap);
+    }
+
+    pub fn get_cap(env: &Env) -> Option<i128> {
+        env.storage().instance().get(&DataKey::Cap)
+    }
+
+    //==== Balances: every read and write bumps the entry's TTL ====//
+    pub fn read_balance(env: &Env, id: &Address) -> i128 {
+        let key = DataKey::Balance(id.clone());
+        match env.storage().persistent().get::<_, i128>(&key) {
+            Some(balance) => {
+                env.storage().persistent().extend_ttl(
+                    &key,
+                    BALANCE_LIFETIME_THRESHOLD,
+                    BALANCE_BUMP_AMOUNT,
+                );
+                balance
+            }
+            None => 0,
+        }
+    }
+
+    pub fn write_balance(env: &Env, id: &Address, amount: i128) {
+        let key = DataKey::Balance(id.clone());
+        env.storage().persistent().set(&key, &amount);
+        env.storage().persistent().extend_ttl(
+            &key,
+            BALANCE_LIFETIME_THRESHOLD,
+            BALANCE_BUMP_AMOUNT,
+        );
+    }
+
+    //==== Frozen accounts: absent means authorized ====//
+    pub fn is_frozen(env: &Env, id: &Address) -> bool {
+        let key = DataKey::Frozen(id.clone());
+        let frozen = env.storage().persistent().has(&key);
+        if frozen {
+            env.storage().persistent().extend_ttl(
+                &key,
+                BALANCE_LIFETIME_THRESHOLD,
+                BALANCE_BUMP_AMOUNT,
+            );
+        }
+        frozen
+    }
+
+    pub fn set_frozen(env: &Env, id: &Address, frozen: bool) {
+        let key = DataKey::Frozen(id.clone());
+        if frozen {
+            env.storage().persistent().set(&key, &true);
+            env.storage().persistent().extend_ttl(
+                &key,
+                BALANCE_LIFETIME_THRESHOLD,
+                BALANCE_BUMP_AMOUNT,
+            );
+        } else {
+            env.storage().persistent().remove(&key);
+        }
+    }
+
+    //==== Allowances: TTL is tied to the expiration ledger, never bumped past it ====//
+    pub fn read_allowance(env: &Env, from: &Address, spender: &Address) -> Option<AllowanceValue> {
+        let key = DataKey::Allowance(AllowanceDataKey {
+            from: from.clone(),
+            spender: spender.clone(),
+        });
+        env.storage().temporary().get(&key)
+    }
+
+    pub fn write_allowance(
+        env: &Env,
+        from: &Address,
+        spender: &Address,
+        allowance: &AllowanceValue,
+    ) {
+        let key = DataKey::Allowance(AllowanceDataKey {
+            from: from.clone(),
+            spender: spender.clone(),
+        });
+        env.storage().temporary().set(&key, allowance);
+
+        if allowance.amount > 0 {
+            // Let the record age out of storage once the approval has expired
+            let live_for = allowance.expiration_ledger - env.ledger().sequence();
+            env.storage()
+                .temporary()
+                .extend_ttl(&key, live_for, live_for);
+        }
+    }
+}
@@ -1,21 +1,558 @@
 #![cfg(test)]
 
-use super::*;
-use soroban_sdk::{vec, Env, String};
+use crate::{
+    errors::TokenError,
+    token_logic::{Token, TokenClient},
+    types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT},
+};
+use soroban_sdk::{
+    testutils::{storage::Instance as _, storage::Persistent as _, Address as _, Ledger},
+    Address, BytesN, Env, String,
+};
+
+fn setup_token(env: &Env) -> (TokenClient<'_>, Address) {
+    setup_token_with_cap(env, None)
+}
+
+fn setup_token_with_cap(env: &Env, cap: Option<i128>) -> (TokenClient<'_>, Address) {
+    let contract_id = env.register(Token, ());
+    let client = TokenClient::new(env, &contract_id);
+    let admin = Address::generate(env);
+
+    client.mock_all_auths().initialize(
+        &admin,
+        &7,
+        &String::from_str(env, "DeCleanup Reward"),
+        &String::from_str(env, "DCU"),
+        &cap,
+    );
+
+    (client, admin)
+}
+
+#[test]
+fn test_initialize_sets_metadata() {
+    let env = Env::default();
+    let (client, _admin) = setup_token(&env);
+
+    assert_eq!(client.decimals(), 7);
+    assert_eq!(client.name(), String::from_str(&env, "DeCleanup Reward"));
+    assert_eq!(client.symbol(), String::from_str(&env, "DCU"));
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call for address")]
+fn test_initialize_without_admin_auth_should_fail() {
+    let env = Env::default();
+    let contract_id = env.register(Token, ());
+    let client = TokenClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    client.initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, "DeCleanup Reward"),
+        &String::from_str(&env, "DCU"),
+        &None,
+    );
+}
+
+#[test]
+fn test_initialize_twice_should_fail() {
+    let env = Env::default();
+    let (client, admin) = setup_token(&env);
+
+    let result = client.try_initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, "Other"),
+        &String::from_str(&env, "OTH"),
+        &None,
+    );
+    assert_eq!(result, Err(Ok(TokenError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_initialize_with_invalid_cap_should_fail() {
+    let env = Env::default();
+    let contract_id = env.register(Token, ());
+    let client = TokenClient::new(&env, &contract_id);
+    let admin = Address::generate(&env);
+
+    let result = client.mock_all_auths().try_initialize(
+        &admin,
+        &7,
+        &String::from_str(&env, "DeCleanup Reward"),
+        &String::from_str(&env, "DCU"),
+        &Some(0),
+    );
+    assert_eq!(result, Err(Ok(TokenError::InvalidCap)));
+}
 
 #[test]
-fn test() {
+fn test_mint_before_initialize_should_fail() {
     let env = Env::default();
-    let contract_id = env.register(Contract, ());
-    let client = ContractClient::new(&env, &contract_id);
+    env.mock_all_auths();
+    let contract_id = env.register(Token, ());
+    let client = TokenClient::new(&env, &contract_id);
+    let user = Address::generate(&env);
+
+    let result = client.try_mint(&user, &1_000);
+    assert_eq!(result, Err(Ok(TokenError::NotInitialized)));
+}
+
+#[test]
+fn test_mint() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_token(&env);
+    let user = Address::generate(&env);
+
+    client.mint(&user, &1_000);
+    assert_eq!(env.auths()[0].0, admin);
+
+    assert_eq!(client.balance(&user), 1_000);
+}
 
-    let words = client.hello(&String::from_str(&env, "Dev"));
+#[test]
+#[should_panic(expected = "Unauthorized function call for address")]
+fn test_mint_without_admin_auth_should_fail() {
+    let env = Env::default();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+
+    client.mint(&user, &1_000);
+}
+
+#[test]
+fn test_transfer_and_burn() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.mint(&user, &1_000);
+    client.transfer(&user, &receiver, &400);
+    client.burn(&receiver, &100);
+
+    assert_eq!(client.balance(&user), 600);
+    assert_eq!(client.balance(&receiver), 300);
+}
+
+#[test]
+fn test_total_supply_tracks_mint_and_burn() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    assert_eq!(client.total_supply(), 0);
+    assert_eq!(client.cap(), None);
+
+    client.mint(&user, &1_000);
+    assert_eq!(client.total_supply(), 1_000);
+
+    client.burn(&user, &200);
+    assert_eq!(client.total_supply(), 800);
+
+    client.approve(&user, &spender, &300, &(env.ledger().sequence() + 100));
+    client.burn_from(&spender, &user, &300);
+    assert_eq!(client.total_supply(), 500);
+
+    // Transfers move balances but leave the supply untouched
+    client.transfer(&user, &spender, &100);
+    assert_eq!(client.total_supply(), 500);
+}
+
+#[test]
+fn test_mint_up_to_cap() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token_with_cap(&env, Some(1_000));
+    let user = Address::generate(&env);
+
+    client.mint(&user, &600);
+    client.mint(&user, &400);
+
+    assert_eq!(client.cap(), Some(1_000));
+    assert_eq!(client.total_supply(), 1_000);
+}
+
+#[test]
+fn test_mint_above_cap_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token_with_cap(&env, Some(1_000));
+    let user = Address::generate(&env);
+
+    client.mint(&user, &600);
+    let result = client.try_mint(&user, &401);
+    assert_eq!(result, Err(Ok(TokenError::SupplyCapExceeded)));
+    assert_eq!(client.total_supply(), 600);
+}
+
+#[test]
+fn test_mint_supply_overflow_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.mint(&user, &i128::MAX);
+    let result = client.try_mint(&other, &1);
+    assert_eq!(result, Err(Ok(TokenError::SupplyOverflow)));
+}
+
+#[test]
+fn test_non_positive_amount_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.mint(&user, &1_000);
+    client.approve(&user, &other, &500, &(env.ledger().sequence() + 10));
+
+    let invalid = Err(Ok(TokenError::InvalidAmount));
+    assert_eq!(client.try_mint(&user, &0), invalid);
+    assert_eq!(client.try_transfer(&user, &other, &0), invalid);
+    assert_eq!(
+        client.try_transfer_from(&other, &user, &other, &-1),
+        invalid
+    );
+    assert_eq!(client.try_burn(&user, &-5), invalid);
+    assert_eq!(client.try_burn_from(&other, &user, &0), invalid);
+    assert_eq!(
+        client.try_approve(&user, &other, &-1, &(env.ledger().sequence() + 10)),
+        invalid
+    );
+}
+
+#[test]
+fn test_insufficient_balance_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.mint(&user, &100);
+    client.approve(&user, &spender, &500, &(env.ledger().sequence() + 10));
+
+    let insufficient = Err(Ok(TokenError::InsufficientBalance));
+    assert_eq!(client.try_transfer(&user, &spender, &101), insufficient);
+    assert_eq!(
+        client.try_transfer_from(&spender, &user, &spender, &101),
+        insufficient
+    );
+    assert_eq!(client.try_burn(&user, &101), insufficient);
+    assert_eq!(client.try_burn_from(&spender, &user, &101), insufficient);
+
+    // Failed calls leave balances and allowances untouched
+    assert_eq!(client.balance(&user), 100);
+    assert_eq!(client.allowance(&user, &spender), 500);
+}
+
+#[test]
+fn test_insufficient_allowance_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.mint(&user, &1_000);
+    client.approve(&user, &spender, &100, &(env.ledger().sequence() + 10));
+
+    let insufficient = Err(Ok(TokenError::InsufficientAllowance));
     assert_eq!(
-        words,
-        vec![
-            &env,
-            String::from_str(&env, "Hello"),
-            String::from_str(&env, "Dev"),
-        ]
+        client.try_transfer_from(&spender, &user, &spender, &101),
+        insufficient
     );
+    assert_eq!(client.try_burn_from(&spender, &user, &101), insufficient);
+}
+
+#[test]
+fn test_allowance_expires() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    let expiration_ledger = env.ledger().sequence() + 10;
+    client.approve(&owner, &spender, &500, &expiration_ledger);
+    assert_eq!(client.allowance(&owner, &spender), 500);
+
+    // Still valid on the expiration ledger itself
+    env.ledger().set_sequence_number(expiration_ledger);
+    assert_eq!(client.allowance(&owner, &spender), 500);
+
+    env.ledger().set_sequence_number(expiration_ledger + 1);
+    assert_eq!(client.allowance(&owner, &spender), 0);
+}
+
+#[test]
+fn test_transfer_from_consumes_allowance() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.mint(&owner, &1_000);
+    client.approve(&owner, &spender, &500, &(env.ledger().sequence() + 10));
+    client.transfer_from(&spender, &owner, &receiver, &200);
+
+    assert_eq!(client.allowance(&owner, &spender), 300);
+    assert_eq!(client.balance(&owner), 800);
+    assert_eq!(client.balance(&receiver), 200);
+}
+
+#[test]
+fn test_approve_with_past_expiration_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    env.ledger().set_sequence_number(100);
+    let result = client.try_approve(&owner, &spender, &500, &99);
+    assert_eq!(result, Err(Ok(TokenError::InvalidExpirationLedger)));
+}
+
+#[test]
+fn test_approve_beyond_max_ttl_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    let max_expiration = env.ledger().sequence() + env.storage().max_ttl();
+    let result = client.try_approve(&owner, &spender, &500, &(max_expiration + 1));
+    assert_eq!(result, Err(Ok(TokenError::InvalidExpirationLedger)));
+
+    client.approve(&owner, &spender, &500, &max_expiration);
+    assert_eq!(client.allowance(&owner, &spender), 500);
+}
+
+#[test]
+fn test_approve_zero_with_past_expiration() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    env.ledger().set_sequence_number(100);
+    client.approve(&owner, &spender, &500, &200);
+    client.approve(&owner, &spender, &0, &99);
+
+    assert_eq!(client.allowance(&owner, &spender), 0);
+}
+
+#[test]
+fn test_transfer_from_with_expired_allowance_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+    let receiver = Address::generate(&env);
+
+    client.mint(&owner, &1_000);
+    let expiration_ledger = env.ledger().sequence() + 10;
+    client.approve(&owner, &spender, &500, &expiration_ledger);
+
+    env.ledger().set_sequence_number(expiration_ledger + 1);
+    let result = client.try_transfer_from(&spender, &owner, &receiver, &100);
+    assert_eq!(result, Err(Ok(TokenError::InsufficientAllowance)));
+}
+
+#[test]
+fn test_burn_from_with_expired_allowance_should_fail() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let owner = Address::generate(&env);
+    let spender = Address::generate(&env);
+
+    client.mint(&owner, &1_000);
+    let expiration_ledger = env.ledger().sequence() + 10;
+    client.approve(&owner, &spender, &500, &expiration_ledger);
+
+    env.ledger().set_sequence_number(expiration_ledger + 1);
+    let result = client.try_burn_from(&spender, &owner, &100);
+    assert_eq!(result, Err(Ok(TokenError::InsufficientAllowance)));
+}
+
+fn balance_ttl(env: &Env, client: &TokenClient, id: &Address) -> u32 {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .persistent()
+            .get_ttl(&DataKey::Balance(id.clone()))
+    })
+}
+
+// Long ledger jumps would also archive the contract instance, which in a live
+// network is kept alive by other traffic; bump it directly so only the balance
+// entry's own TTL is under test.
+fn keep_instance_alive(env: &Env, client: &TokenClient) {
+    env.as_contract(&client.address, || {
+        env.storage()
+            .instance()
+            .extend_ttl(2 * BALANCE_BUMP_AMOUNT, 2 * BALANCE_BUMP_AMOUNT)
+    });
+}
+
+#[test]
+fn test_balance_ttl_is_bumped_on_write() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+
+    client.mint(&user, &1_000);
+
+    assert_eq!(balance_ttl(&env, &client, &user), BALANCE_BUMP_AMOUNT);
+    let instance_ttl = env.as_contract(&client.address, || env.storage().instance().get_ttl());
+    assert_eq!(instance_ttl, INSTANCE_BUMP_AMOUNT);
+}
+
+#[test]
+fn test_balance_survives_past_default_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+
+    client.mint(&user, &1_000);
+    keep_instance_alive(&env, &client);
+
+    // Well past the network's minimum persistent TTL, but inside our bump
+    let sequence = env.ledger().sequence();
+    env.ledger()
+        .set_sequence_number(sequence + BALANCE_BUMP_AMOUNT - 1);
+
+    assert_eq!(client.balance(&user), 1_000);
+}
+
+#[test]
+fn test_balance_read_extends_ttl() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+
+    client.mint(&user, &1_000);
+    keep_instance_alive(&env, &client);
+
+    // Once the remaining TTL drops under the threshold, a plain read bumps it
+    let sequence = env.ledger().sequence();
+    let elapsed = BALANCE_BUMP_AMOUNT - BALANCE_LIFETIME_THRESHOLD + 1;
+    env.ledger().set_sequence_number(sequence + elapsed);
+    assert!(balance_ttl(&env, &client, &user) < BALANCE_LIFETIME_THRESHOLD);
+
+    assert_eq!(client.balance(&user), 1_000);
+    assert_eq!(balance_ttl(&env, &client, &user), BALANCE_BUMP_AMOUNT);
+
+    // Advance past the original TTL; the bumped entry is still live
+    env.ledger()
+        .set_sequence_number(sequence + BALANCE_BUMP_AMOUNT + 1);
+    assert_eq!(client.balance(&user), 1_000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Storage, InternalError)")]
+fn test_balance_is_archived_without_bump() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+
+    client.mint(&user, &1_000);
+    keep_instance_alive(&env, &client);
+
+    let sequence = env.ledger().sequence();
+    env.ledger()
+        .set_sequence_number(sequence + BALANCE_BUMP_AMOUNT + 1);
+
+    client.balance(&user);
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call for address")]
+fn test_upgrade_without_admin_auth_should_fail() {
+    let env = Env::default();
+    let (client, _admin) = setup_token(&env);
+
+    client.upgrade(&BytesN::from_array(&env, &[0; 32]));
+}
+
+#[test]
+fn test_clawback() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, admin) = setup_token(&env);
+    let user = Address::generate(&env);
+
+    client.mint(&user, &1_000);
+    client.clawback(&user, &400);
+    assert_eq!(env.auths()[0].0, admin);
+
+    assert_eq!(client.balance(&user), 600);
+    assert_eq!(client.total_supply(), 600);
+    assert_eq!(
+        client.try_clawback(&user, &601),
+        Err(Ok(TokenError::InsufficientBalance))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Unauthorized function call for address")]
+fn test_clawback_without_admin_auth_should_fail() {
+    let env = Env::default();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+
+    client.clawback(&user, &1);
+}
+
+#[test]
+fn test_frozen_account_is_rejected() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let (client, _admin) = setup_token(&env);
+    let user = Address::generate(&env);
+    let other = Address::generate(&env);
+
+    client.mint(&user, &1_000);
+    client.mint(&other, &1_000);
+    client.approve(&user, &other, &500, &(env.ledger().sequence() + 10));
+    client.set_authorized(&user, &false);
+    assert!(!client.authorized(&user));
+
+    let frozen = Err(Ok(TokenError::AccountFrozen));
+    assert_eq!(client.try_transfer(&user, &other, &1), frozen);
+    assert_eq!(client.try_transfer(&other, &user, &1), frozen);
+    assert_eq!(client.try_transfer_from(&other, &user, &other, &1), frozen);
+    assert_eq!(client.try_burn(&user, &1), frozen);
+    assert_eq!(client.try_burn_from(&other, &user, &1), frozen);
+    assert_eq!(client.try_mint(&user, &1), frozen);
+
+    // The admin can still claw back from a frozen account
+    client.clawback(&user, &1_000);
+    assert_eq!(client.balance(&user), 0);
+
+    client.set_authorized(&user, &true);
+    assert!(client.authorized(&user));
+    client.transfer(&other, &user, &1);
+    assert_eq!(client.balance(&user), 1);
 }
@@ -1,11 +1,102 @@
-use soroban_sdk::{contract, contractimpl, symbol_short, Address, Env, String, Symbol};
+use soroban_sdk::{contract, contractimpl, symbol_short, Address, BytesN, Env, String, Symbol};
+
+use crate::{
+    errors::TokenError, storage::TokenStorageLayer, token_trait::TokenInterface,
+    types::AllowanceValue,
+};
+
+//==== Allowance helpers (apply the SEP-41 expiry rules in one place) ====//
+fn read_allowance(env: &Env, from: &Address, spender: &Address) -> AllowanceValue {
+    match TokenStorageLayer::read_allowance(env, from, spender) {
+        // An expired allowance is treated as a 0 amount allowance
+        Some(allowance) if allowance.expiration_ledger < env.ledger().sequence() => {
+            AllowanceValue {
+                amount: 0,
+                expiration_ledger: allowance.expiration_ledger,
+            }
+        }
+        Some(allowance) => allowance,
+        None => AllowanceValue {
+            amount: 0,
+            expiration_ledger: 0,
+        },
+    }
+}
+
+fn write_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+) -> Result<(), TokenError> {
+    if amount < 0 {
+        return Err(TokenError::InvalidAmount);
+    }
+    if amount > 0 {
+        let sequence = env.ledger().sequence();
+        // The allowance entry cannot be kept alive past the network's max TTL
+        if expiration_ledger < sequence || expiration_ledger - sequence > env.storage().max_ttl() {
+            return Err(TokenError::InvalidExpirationLedger);
+        }
+    }
+
+    let allowance = AllowanceValue {
+        amount,
+        expiration_ledger,
+    };
+    TokenStorageLayer::write_allowance(env, from, spender, &allowance);
+    Ok(())
+}
 
-use crate::token_trait::TokenInterface;
+fn spend_allowance(
+    env: &Env,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+) -> Result<(), TokenError> {
+    let allowance = read_allowance(env, from, spender);
+    if allowance.amount < amount {
+        return Err(TokenError::InsufficientAllowance);
+    }
+    write_allowance(
+        env,
+        from,
+        spender,
+        allowance.amount - amount,
+        allowance.expiration_ledger,
+    )
+}
+
+//==== Balance helpers (shared by every path that moves tokens) ====//
+fn spend_balance(env: &Env, from: &Address, amount: i128) -> Result<(), TokenError> {
+    let from_balance = TokenStorageLayer::read_balance(env, from);
+    if from_balance < amount {
+        return Err(TokenError::InsufficientBalance);
+    }
+    TokenStorageLayer::write_balance(env, from, from_balance - amount);
+    Ok(())
+}
+
+fn receive_balance(env: &Env, to: &Address, amount: i128) {
+    let to_balance = TokenStorageLayer::read_balance(env, to);
+    TokenStorageLayer::write_balance(env, to, to_balance + amount);
+}
+
+fn check_authorized(env: &Env, id: &Address) -> Result<(), TokenError> {
+    if TokenStorageLayer::is_frozen(env, id) {
+        return Err(TokenError::AccountFrozen);
+    }
+    Ok(())
+}
+
+fn check_positive(amount: i128) -> Result<(), TokenError> {
+    if amount <= 0 {
+        return Err(TokenError::InvalidAmount);
+    }
+    Ok(())
+}
 
-//==== Constants for storage keys ====//
-const STORAGE_NAME: Symbol = symbol_short!("NAME");
-const STORAGE_SYMBOL: Symbol = symbol_short!("SYMBOL");
-const STORAGE_DECIMALS: Symbol = symbol_short!("DECIMALS");
 //==== Token contract struct ====//
 #[contract]
 pub struct Token;
@@ -15,152 +106,264 @@ pub struct Token;
 impl TokenInterface for Token {
     //==== Allowance Function ====//
     fn allowance(env: Env, from: Address, spender: Address) -> i128 {
-        let key = (symbol_short!("allowance"), from, spender);
-        env.storage().persistent().get(&key).unwrap_or(0)
+        TokenStorageLayer::extend_instance(&env);
+        read_allowance(&env, &from, &spender).amount
     }
     //==== Approve Function (Sets allowance and publishes event) ====//
-    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32) {
+    fn approve(
+        env: Env,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), TokenError> {
         from.require_auth();
+        TokenStorageLayer::extend_instance(&env);
 
-        let key = (symbol_short!("allowance"), from.clone(), spender.clone());
-        env.storage().persistent().set(&key, &amount);
-
-        let expiration_key = (symbol_short!("expire"), from.clone(), spender.clone());
-        env.storage()
-            .persistent()
-            .set(&expiration_key, &expiration_ledger);
+        write_allowance(&env, &from, &spender, amount, expiration_ledger)?;
 
         env.events().publish(
             (symbol_short!("approve"), from, spender),
             (amount, expiration_ledger),
         );
+        Ok(())
     }
 
     //==== Balance Function (Returns balance of an address) ====//
     fn balance(env: Env, id: Address) -> i128 {
-        let key = (symbol_short!("balance"), id);
-        env.storage().persistent().get(&key).unwrap_or(0)
+        TokenStorageLayer::extend_instance(&env);
+        TokenStorageLayer::read_balance(&env, &id)
     }
 
     //==== transfer function ====//
-    fn transfer(env: Env, from: Address, to: Address, amount: i128) {
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), TokenError> {
         from.require_auth(); //==== Ensure the 'from' address has authorization to perform the transfer.
+        TokenStorageLayer::extend_instance(&env);
 
-        let from_key = (symbol_short!("balance"), from.clone());
-        let mut from_balance = env.storage().persistent().get(&from_key).unwrap_or(0);
-        assert!(from_balance >= amount, "insufficient balance");
-        assert!(amount > 0, "Amount must be positive");
-
-        from_balance -= amount;
-        env.storage().persistent().set(&from_key, &from_balance);
-
-        let to_key = (symbol_short!("balance"), to.clone());
-        let mut to_balance = env.storage().persistent().get(&to_key).unwrap_or(0);
-        to_balance += amount;
-        env.storage().persistent().set(&to_key, &to_balance);
+        check_positive(amount)?;
+        check_authorized(&env, &from)?;
+        check_authorized(&env, &to)?;
+        spend_balance(&env, &from, amount)?;
+        receive_balance(&env, &to, amount);
 
         env.events()
             .publish((symbol_short!("transfer"), from, to), amount);
+        Ok(())
     }
 
     //==== transfer_from function ====//
-    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128) {
+    fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), TokenError> {
         spender.require_auth();
+        TokenStorageLayer::extend_instance(&env);
 
-        let allowance_key = (symbol_short!("allowance"), from.clone(), spender.clone());
-        let mut allowance = env.storage().persistent().get(&allowance_key).unwrap_or(0);
-
-        // Check if the allowance has expired
-        let expiration_key = (symbol_short!("expire"), from.clone(), spender.clone());
-        if let Some(expiration_ledger) = env.storage().persistent().get(&expiration_key) {
-            let current_block = env.ledger().sequence();
-            assert!(current_block <= expiration_ledger, "Allowance expired");
-        }
+        //==== Check if there is enough unexpired allowance for the transfer ====//
+        check_positive(amount)?;
+        check_authorized(&env, &from)?;
+        check_authorized(&env, &to)?;
+        spend_allowance(&env, &from, &spender, amount)?;
 
-        //==== Check if there is enough allowance for the transfer ====//
-        assert!(allowance >= amount, "insufficient allowance");
-        assert!(amount > 0, "Amount must be positive");
-
-        allowance -= amount;
-        env.storage().persistent().set(&allowance_key, &allowance);
-
-        let from_key = (symbol_short!("balance"), from.clone());
-        let mut from_balance = env.storage().persistent().get(&from_key).unwrap_or(0);
-        assert!(from_balance >= amount, "insufficient balance");
-
-        from_balance -= amount;
-        env.storage().persistent().set(&from_key, &from_balance);
-
-        let to_key = (symbol_short!("balance"), to.clone());
-        let mut to_balance = env.storage().persistent().get(&to_key).unwrap_or(0);
-        to_balance += amount;
-        env.storage().persistent().set(&to_key, &to_balance);
+        spend_balance(&env, &from, amount)?;
+        receive_balance(&env, &to, amount);
 
         // Emit the transfer event
         env.events()
             .publish((symbol_short!("transfer"), from, to), amount);
+        Ok(())
     }
 
     //==== burn function ====//
-    fn burn(env: Env, from: Address, amount: i128) {
+    fn burn(env: Env, from: Address, amount: i128) -> Result<(), TokenError> {
         from.require_auth(); //==== Ensure the 'from' address has authorization to burn the tokens.
+        TokenStorageLayer::extend_instance(&env);
 
-        let from_key = (symbol_short!("balance"), from.clone());
-        let mut from_balance = env.storage().persistent().get(&from_key).unwrap_or(0);
-        assert!(from_balance >= amount, "insufficient balance");
-        assert!(amount > 0, "Amount must be positive");
+        check_positive(amount)?;
+        check_authorized(&env, &from)?;
+        spend_balance(&env, &from, amount)?;
 
-        from_balance -= amount;
-        env.storage().persistent().set(&from_key, &from_balance);
+        TokenStorageLayer::set_total_supply(
+            &env,
+            TokenStorageLayer::get_total_supply(&env) - amount,
+        );
 
         // Emit burn event
         env.events().publish((symbol_short!("burn"), from), amount);
+        Ok(())
     }
 
     //==== burn_from function ====//
-    fn burn_from(env: Env, spender: Address, from: Address, amount: i128) {
+    fn burn_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        amount: i128,
+    ) -> Result<(), TokenError> {
         spender.require_auth(); //==== Ensure the spender has authorization to burn the tokens on behalf of 'from'.
+        TokenStorageLayer::extend_instance(&env);
 
-        let allowance_key = (symbol_short!("allowance"), from.clone(), spender.clone());
-        let mut allowance = env.storage().persistent().get(&allowance_key).unwrap_or(0);
-        assert!(allowance >= amount, "insufficient allowance");
-        assert!(amount > 0, "Amount must be positive");
-
-        allowance -= amount;
-        env.storage().persistent().set(&allowance_key, &allowance);
+        check_positive(amount)?;
+        check_authorized(&env, &from)?;
+        spend_allowance(&env, &from, &spender, amount)?;
+        spend_balance(&env, &from, amount)?;
 
-        let from_key = (symbol_short!("balance"), from.clone());
-        let mut from_balance = env.storage().persistent().get(&from_key).unwrap_or(0);
-        assert!(from_balance >= amount, "insufficient balance");
-
-        from_balance -= amount;
-        env.storage().persistent().set(&from_key, &from_balance);
+        TokenStorageLayer::set_total_supply(
+            &env,
+            TokenStorageLayer::get_total_supply(&env) - amount,
+        );
 
         // Emit burn event
         env.events().publish((symbol_short!("burn"), from), amount);
+        Ok(())
     }
 
     //==== decimals function ====//
     fn decimals(env: Env) -> u32 {
-        env.storage()
-            .persistent()
-            .get(&STORAGE_DECIMALS)
-            .unwrap_or(18) // Default to 18 if not set
+        TokenStorageLayer::extend_instance(&env);
+        TokenStorageLayer::get_decimals(&env).unwrap_or(18) // Default to 18 if not set
     }
 
     //==== name function ====//
     fn name(env: Env) -> String {
-        env.storage()
-            .persistent()
-            .get(&STORAGE_NAME)
-            .unwrap_or_else(|| String::from_str(&env, "My Token")) // Default to "My Token" if not initialized
+        TokenStorageLayer::extend_instance(&env);
+        TokenStorageLayer::get_name(&env).unwrap_or_else(|| String::from_str(&env, "My Token"))
+        // Default to "My Token" if not initialized
     }
 
     //==== symbol function ====//
     fn symbol(env: Env) -> String {
-        env.storage()
-            .persistent()
-            .get(&STORAGE_SYMBOL)
-            .unwrap_or_else(|| String::from_str(&env, "MYTOKEN")) // Default to "MYTOKEN" if not initialized
+        TokenStorageLayer::extend_instance(&env);
+        TokenStorageLayer::get_symbol(&env).unwrap_or_else(|| String::from_str(&env, "MYTOKEN"))
+        // Default to "MYTOKEN" if not initialized
+    }
+}
+
+//==== Admin functions (not part of the SEP-41 interface) ====//
+#[contractimpl]
+impl Token {
+    //==== initialize function (one-shot, sets admin and metadata) ====//
+    pub fn initialize(
+        env: Env,
+        admin: Address,
+        decimals: u32,
+        name: String,
+        symbol: String,
+        cap: Option<i128>,
+    ) -> Result<(), TokenError> {
+        if TokenStorageLayer::has_admin(&env) {
+            return Err(TokenError::AlreadyInitialized);
+        }
+        admin.require_auth(); //==== The admin must accept control of the token.
+        if let Some(cap) = cap {
+            if cap <= 0 {
+                return Err(TokenError::InvalidCap);
+            }
+            TokenStorageLayer::set_cap(&env, cap);
+        }
+
+        TokenStorageLayer::set_admin(&env, &admin);
+        TokenStorageLayer::set_metadata(&env, decimals, &name, &symbol);
+        TokenStorageLayer::set_total_supply(&env, 0);
+        TokenStorageLayer::extend_instance(&env);
+        Ok(())
+    }
+
+    //==== mint function (admin only) ====//
+    pub fn mint(env: Env, to: Address, amount: i128) -> Result<(), TokenError> {
+        let admin = TokenStorageLayer::retrieve_admin(&env).ok_or(TokenError::NotInitialized)?;
+        admin.require_auth(); //==== Only the admin can create new supply.
+        TokenStorageLayer::extend_instance(&env);
+
+        check_positive(amount)?;
+        check_authorized(&env, &to)?;
+
+        let supply = TokenStorageLayer::get_total_supply(&env)
+            .checked_add(amount)
+            .ok_or(TokenError::SupplyOverflow)?;
+        if let Some(cap) = TokenStorageLayer::get_cap(&env) {
+            if supply > cap {
+                return Err(TokenError::SupplyCapExceeded);
+            }
+        }
+        TokenStorageLayer::set_total_supply(&env, supply);
+        receive_balance(&env, &to, amount);
+
+        // Emit mint event
+        env.events()
+            .publish((symbol_short!("mint"), admin, to), amount);
+        Ok(())
+    }
+
+    //==== clawback function (admin only, works on frozen accounts) ====//
+    pub fn clawback(env: Env, from: Address, amount: i128) -> Result<(), TokenError> {
+        let admin = TokenStorageLayer::retrieve_admin(&env).ok_or(TokenError::NotInitialized)?;
+        admin.require_auth(); //==== Only the admin can reclaim fraudulently earned tokens.
+        TokenStorageLayer::extend_instance(&env);
+
+        check_positive(amount)?;
+        spend_balance(&env, &from, amount)?;
+
+        TokenStorageLayer::set_total_supply(
+            &env,
+            TokenStorageLayer::get_total_supply(&env) - amount,
+        );
+
+        // Emit clawback event
+        env.events()
+            .publish((symbol_short!("clawback"), admin, from), amount);
+        Ok(())
+    }
+
+    //==== set_authorized function (admin only, false freezes the account) ====//
+    pub fn set_authorized(env: Env, id: Address, authorize: bool) -> Result<(), TokenError> {
+        let admin = TokenStorageLayer::retrieve_admin(&env).ok_or(TokenError::NotInitialized)?;
+        admin.require_auth();
+        TokenStorageLayer::extend_instance(&env);
+
+        TokenStorageLayer::set_frozen(&env, &id, !authorize);
+
+        // Emit set_authorized event
+        env.events()
+            .publish((Symbol::new(&env, "set_authorized"), admin, id), authorize);
+        Ok(())
+    }
+
+    //==== authorized function (false while the account is frozen) ====//
+    pub fn authorized(env: Env, id: Address) -> bool {
+        TokenStorageLayer::extend_instance(&env);
+        !TokenStorageLayer::is_frozen(&env, &id)
+    }
+
+    //==== total_supply function (minted minus burned) ====//
+    pub fn total_supply(env: Env) -> i128 {
+        TokenStorageLayer::extend_instance(&env);
+        TokenStorageLayer::get_total_supply(&env)
+    }
+
+    //==== cap function (None when supply is uncapped) ====//
+    pub fn cap(env: Env) -> Option<i128> {
+        TokenStorageLayer::extend_instance(&env);
+        TokenStorageLayer::get_cap(&env)
+    }
+
+    //==== upgrade function (admin only, replaces the contract wasm) ====//
+    /// Replaces the contract code with the uploaded wasm `new_wasm_hash`,
+    /// keeping storage. Tokens deployed from builds without `upgrade` cannot
+    /// replace their wasm and have to be redeployed instead.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) -> Result<(), TokenError> {
+        let admin = TokenStorageLayer::retrieve_admin(&env).ok_or(TokenError::NotInitialized)?;
+        admin.require_auth();
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        // Emit upgrade event
+        env.events()
+            .publish((symbol_short!("upgrade"), admin), new_wasm_hash);
+        Ok(())
     }
 }
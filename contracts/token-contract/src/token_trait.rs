@@ -1,4 +1,7 @@
 use soroban_sdk::{Address, Env, String};
+
+use crate::errors::TokenError;
+
 pub trait TokenInterface {
     /// Returns the allowance for `spender` to transfer from `from`.
     ///
@@ -18,15 +21,21 @@ pub trait TokenInterface {
     ///   `from`.
     /// * `amount` - The tokens to be made available to `spender`.
     /// * `expiration_ledger` - The ledger number where this allowance expires. Cannot
-    ///    be less than the current ledger number unless the amount is being set to 0.
-    ///    An expired entry (where expiration_ledger < the current ledger number)
-    ///    should be treated as a 0 amount allowance.
+    ///   be less than the current ledger number unless the amount is being set to 0.
+    ///   An expired entry (where expiration_ledger < the current ledger number)
+    ///   should be treated as a 0 amount allowance.
     ///
     /// # Events
     ///
     /// Emits an event with topics `["approve", from: Address,
     /// spender: Address], data = [amount: i128, expiration_ledger: u32]`
-    fn approve(env: Env, from: Address, spender: Address, amount: i128, expiration_ledger: u32);
+    fn approve(
+        env: Env,
+        from: Address,
+        spender: Address,
+        amount: i128,
+        expiration_ledger: u32,
+    ) -> Result<(), TokenError>;
 
     /// Returns the balance of `id`.
     ///
@@ -49,7 +58,7 @@ pub trait TokenInterface {
     ///
     /// Emits an event with topics `["transfer", from: Address, to: Address],
     /// data = amount: i128`
-    fn transfer(env: Env, from: Address, to: Address, amount: i128);
+    fn transfer(env: Env, from: Address, to: Address, amount: i128) -> Result<(), TokenError>;
 
     /// Transfer `amount` from `from` to `to`, consuming the allowance of
     /// `spender`. Authorized by spender (`spender.require_auth()`).
@@ -67,7 +76,13 @@ pub trait TokenInterface {
     ///
     /// Emits an event with topics `["transfer", from: Address, to: Address],
     /// data = amount: i128`
-    fn transfer_from(env: Env, spender: Address, from: Address, to: Address, amount: i128);
+    fn transfer_from(
+        env: Env,
+        spender: Address,
+        from: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), TokenError>;
 
     /// Burn `amount` from `from`.
     ///
@@ -81,7 +96,7 @@ pub trait TokenInterface {
     ///
     /// Emits an event with topics `["burn", from: Address], data = amount:
     /// i128`
-    fn burn(env: Env, from: Address, amount: i128);
+    fn burn(env: Env, from: Address, amount: i128) -> Result<(), TokenError>;
 
     /// Burn `amount` from `from`, consuming the allowance of `spender`.
     ///
@@ -97,7 +112,8 @@ pub trait TokenInterface {
     ///
     /// Emits an event with topics `["burn", from: Address], data = amount:
     /// i128`
-    fn burn_from(env: Env, spender: Address, from: Address, amount: i128);
+    fn burn_from(env: Env, spender: Address, from: Address, amount: i128)
+        -> Result<(), TokenError>;
 
     /// Returns the number of decimals used to represent amounts of this token.
     ///
@@ -0,0 +1,42 @@
+use soroban_sdk::{contracttype, Address};
+
+//==== Balance TTLs outlast the instance bump: holders may go months between transfers ====//
+pub const DAY_IN_LEDGERS: u32 = 17280;
+pub const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+pub const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+pub const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+//==== Allowance record (amount and expiry are always stored together) ====//
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowanceValue {
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub struct AllowanceDataKey {
+    pub from: Address,
+    pub spender: Address,
+}
+
+/// Storage layout:
+///
+/// * instance: `Admin`, `Decimals`, `Name`, `Symbol`, `TotalSupply`, `Cap`
+/// * persistent: `Balance(id)`, `Frozen(id)` (present only while frozen)
+/// * temporary: `Allowance(from, spender)`, live until its expiration ledger
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    Decimals,
+    Name,
+    Symbol,
+    TotalSupply,
+    Cap,
+    Balance(Address),
+    Allowance(AllowanceDataKey),
+    Frozen(Address),
+}